@@ -7,6 +7,31 @@ pub struct Board {
     pub white_king_position: Option<Position>,
     pub black_king_position: Option<Position>,
 }
+
+/// PositionIssue
+/// A reason a position on the board could never happen in a real game.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum PositionIssue {
+    ///The player has no king
+    MissingKing(Color),
+    ///The player has more than one king
+    TooManyKings(Color),
+    ///A pawn stands on the first or last rank, where pawns can never be
+    PawnOnBackRank(BoardPosition),
+    ///The player has more than 16 pieces
+    TooManyPieces(Color),
+    ///The player has more queens, rooks, bishops or knights than their missing pawns could have been promoted to
+    ImpossiblePromotions(Color),
+    ///The player who is not to move is in check, so their king could be captured
+    OpponentInCheck(Color),
+}
+
+impl Default for Board {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 // Board has a 2D array that is first indexed by the rank then the file.
 // This is so that I can loop through a whole row, instead of columns.
 impl Board {
@@ -75,12 +100,10 @@ impl Board {
     /// If it is the king, the king position is also removed
     pub fn despawn_piece(&mut self, position: &Position) {
         //check to see if the piece is a king and remove the king position
-        if let Some(piece) = self.get_piece(position) {
-            if let King(color) = piece {
-                match color {
-                    Color::White => self.white_king_position = None,
-                    Color::Black => self.black_king_position = None,
-                }
+        if let Some(King(color)) = self.get_piece(position) {
+            match color {
+                Color::White => self.white_king_position = None,
+                Color::Black => self.black_king_position = None,
             }
         }
         self.piece_array[(7usize).abs_diff(position.y)][position.x] = None;
@@ -109,19 +132,152 @@ impl Board {
         }
     }
 
+    ///Returns the positions of all pieces of the specified color that attack the specified position.
+    /// A piece attacks a square if it could capture an enemy piece standing there,
+    /// so pawns attack diagonally forward and never straight ahead.
+    pub fn attackers_of(&self, position: &Position, by_color: Color) -> Vec<Position> {
+        let mut attackers = Vec::new();
+
+        //Pawns attack diagonally forward, so look diagonally backwards from the square
+        for action in [
+            moveset::Move::ForwardLeft(by_color.other()),
+            moveset::Move::ForwardRight(by_color.other()),
+        ] {
+            if let Some(p) = action.get_position(position, 1) {
+                if self.get_piece(&p) == Some(Pawn(by_color)) {
+                    attackers.push(p);
+                }
+            }
+        }
+
+        //Knights and kings attack the squares they can step to
+        for piece in [Knight(by_color), King(by_color)] {
+            for action in moveset::get_moveset(piece).moves {
+                if let Some(p) = action.get_position(position, 1) {
+                    if self.get_piece(&p) == Some(piece) {
+                        attackers.push(p);
+                    }
+                }
+            }
+        }
+
+        //Rooks, bishops and queens attack along their lines until the first piece in the way
+        for piece in [Rook(by_color), Bishop(by_color)] {
+            let moveset = moveset::get_moveset(piece);
+            for action in moveset.moves {
+                for p in moveset::get_steps(position, &action, moveset.steps) {
+                    if let Some(found) = self.get_piece(&p) {
+                        if found == piece || found == Queen(by_color) {
+                            attackers.push(p);
+                        }
+                        break;
+                    }
+                }
+            }
+        }
+        attackers
+    }
+
+    ///Returns true if any piece of the specified color attacks the specified position
+    pub fn is_square_attacked(&self, position: &Position, by_color: Color) -> bool {
+        !self.attackers_of(position, by_color).is_empty()
+    }
+
     ///Returns all pieces on the board
     pub fn get_all_pieces(&self) -> Vec<&Piece> {
         let mut pieces = Vec::new();
         for rank in self.piece_array.iter() {
-            for piece in rank.iter() {
-                if let Some(p) = piece {
-                    pieces.push(p);
-                }
+            for p in rank.iter().flatten() {
+                pieces.push(p);
             }
         }
         pieces
     }
 
+    ///Returns the positions of all pieces on the board of the specified color
+    pub fn get_positions_of_color(&self, color: Color) -> Vec<Position> {
+        let mut positions = Vec::new();
+        for (y, rank) in self.piece_array.iter().enumerate() {
+            for (x, piece) in rank.iter().enumerate() {
+                if piece.is_some_and(|p| p.get_color() == color) {
+                    positions.push(Position::new(x, (7usize).abs_diff(y)));
+                }
+            }
+        }
+        positions
+    }
+
+    ///Returns true if neither player has enough pieces left to ever checkmate.
+    /// That is king against king, king and a bishop or knight against king,
+    /// or kings with bishops that all stand on squares of the same color.
+    pub fn is_insufficient_material(&self) -> bool {
+        let mut minor_pieces = 0;
+        let mut bishop_square_colors = Vec::new();
+        for (y, rank) in self.piece_array.iter().enumerate() {
+            for (x, piece) in rank.iter().enumerate() {
+                match piece {
+                    None | Some(King(_)) => {}
+                    Some(Knight(_)) => minor_pieces += 1,
+                    Some(Bishop(_)) => {
+                        minor_pieces += 1;
+                        bishop_square_colors.push((x + y) % 2);
+                    }
+                    _ => return false,
+                }
+            }
+        }
+
+        let same_colored_bishops = bishop_square_colors.len() == minor_pieces
+            && bishop_square_colors.windows(2).all(|w| w[0] == w[1]);
+        minor_pieces <= 1 || same_colored_bishops
+    }
+
+    ///Returns every reason the position could never happen in a game where it is `side_to_move`'s turn.
+    /// The list is empty if the position is possible as far as the pieces on the board can tell.
+    pub fn validate(&self, side_to_move: Color) -> Vec<PositionIssue> {
+        let mut issues = Vec::new();
+
+        for color in [Color::White, Color::Black] {
+            let pieces = self.get_all_pieces_of_color(color);
+            let count = |kind: PieceKind| pieces.iter().filter(|p| p.kind() == kind).count();
+
+            match count(PieceKind::King) {
+                0 => issues.push(PositionIssue::MissingKing(color)),
+                1 => {}
+                _ => issues.push(PositionIssue::TooManyKings(color)),
+            }
+            if pieces.len() > 16 {
+                issues.push(PositionIssue::TooManyPieces(color));
+            }
+
+            //Every piece more than the starting pieces must be a promoted pawn
+            let promoted = count(PieceKind::Queen).saturating_sub(1)
+                + count(PieceKind::Rook).saturating_sub(2)
+                + count(PieceKind::Bishop).saturating_sub(2)
+                + count(PieceKind::Knight).saturating_sub(2);
+            if count(PieceKind::Pawn) + promoted > 8 {
+                issues.push(PositionIssue::ImpossiblePromotions(color));
+            }
+        }
+
+        for rank in [Rank::One, Rank::Eight] {
+            for (x, piece) in self.get_rank(rank).iter().enumerate() {
+                if let Some(Pawn(_)) = piece {
+                    let file = File::try_from(x).unwrap();
+                    issues.push(PositionIssue::PawnOnBackRank(BoardPosition::new(file, rank)));
+                }
+            }
+        }
+
+        let opponent = side_to_move.other();
+        if let Some(king_position) = self.get_king_position(opponent) {
+            if self.is_square_attacked(&king_position, side_to_move) {
+                issues.push(PositionIssue::OpponentInCheck(opponent));
+            }
+        }
+        issues
+    }
+
     ///Returns all pieces on the board of the specified color
     pub fn get_all_pieces_of_color(&self, color: Color) -> Vec<&Piece> {
         let mut pieces = self.get_all_pieces();
@@ -133,24 +289,24 @@ impl Board {
 impl fmt::Display for Board {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         for (y, rank) in self.piece_array.iter().enumerate() {
-            write!(f, "\n")?;
+            writeln!(f)?;
             for (x, _file) in rank.iter().enumerate() {
                 let pos: BoardPosition = Position::new(x, (7usize).abs_diff(y)).try_into().unwrap();
                 write!(f, " {pos:?}")?;
             }
         }
-        write!(f, "\n")
+        writeln!(f)
     }
 }
 
 impl fmt::Debug for Board {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         for (y, rank) in self.piece_array.iter().enumerate() {
-            write!(f, "\n")?;
+            writeln!(f)?;
             for (x, _file) in rank.iter().enumerate() {
                 write!(f, "[{}][{}] ", x, y)?;
             }
         }
-        write!(f, "\n")
+        writeln!(f)
     }
 }
\ No newline at end of file
@@ -0,0 +1,97 @@
+use crate::*;
+
+///CastlingSide
+/// The side of the board a king castles towards.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum CastlingSide {
+    Kingside,
+    Queenside,
+}
+
+impl CastlingSide {
+    /// Returns the file the rook starts on
+    pub fn rook_file(&self) -> File {
+        match self {
+            Self::Kingside => File::H,
+            Self::Queenside => File::A,
+        }
+    }
+
+    /// Returns the file the king ends up on after castling
+    pub fn king_destination_file(&self) -> File {
+        match self {
+            Self::Kingside => File::G,
+            Self::Queenside => File::C,
+        }
+    }
+
+    /// Returns the file the rook ends up on after castling.
+    /// This is also the square the king passes over.
+    pub fn rook_destination_file(&self) -> File {
+        match self {
+            Self::Kingside => File::F,
+            Self::Queenside => File::D,
+        }
+    }
+
+    /// Returns the files between the king and the rook, these must be empty to castle
+    pub fn files_between(&self) -> &'static [File] {
+        match self {
+            Self::Kingside => &[File::F, File::G],
+            Self::Queenside => &[File::B, File::C, File::D],
+        }
+    }
+}
+
+///CastlingRights
+/// Keeps track of which sides each player is still allowed to castle towards.
+/// A right is lost for good when the king or the rook moves, or when the rook is captured.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct CastlingRights {
+    pub white_kingside: bool,
+    pub white_queenside: bool,
+    pub black_kingside: bool,
+    pub black_queenside: bool,
+}
+
+impl CastlingRights {
+    /// Both players may castle to both sides, as in the starting position
+    pub fn all() -> Self {
+        CastlingRights {
+            white_kingside: true,
+            white_queenside: true,
+            black_kingside: true,
+            black_queenside: true,
+        }
+    }
+
+    /// No player may castle
+    pub fn none() -> Self {
+        CastlingRights {
+            white_kingside: false,
+            white_queenside: false,
+            black_kingside: false,
+            black_queenside: false,
+        }
+    }
+
+    /// Returns true if the color still has the right to castle towards the side
+    pub fn has(&self, color: Color, side: CastlingSide) -> bool {
+        match (color, side) {
+            (Color::White, CastlingSide::Kingside) => self.white_kingside,
+            (Color::White, CastlingSide::Queenside) => self.white_queenside,
+            (Color::Black, CastlingSide::Kingside) => self.black_kingside,
+            (Color::Black, CastlingSide::Queenside) => self.black_queenside,
+        }
+    }
+
+    /// Removes the right of the color to castle towards the side
+    pub fn revoke(&mut self, color: Color, side: CastlingSide) {
+        match (color, side) {
+            (Color::White, CastlingSide::Kingside) => self.white_kingside = false,
+            (Color::White, CastlingSide::Queenside) => self.white_queenside = false,
+            (Color::Black, CastlingSide::Kingside) => self.black_kingside = false,
+            (Color::Black, CastlingSide::Queenside) => self.black_queenside = false,
+        }
+    }
+}
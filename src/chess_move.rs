@@ -0,0 +1,89 @@
+use crate::*;
+
+///MoveKind
+/// What kind of move a move is, apart from its promotion.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum MoveKind {
+    ///The piece moves to an empty square
+    Quiet,
+    ///A pawn moves two steps from its starting rank
+    DoubleStep,
+    ///The piece captures the enemy piece on the destination square
+    Capture,
+    ///A pawn captures the pawn that just double-stepped past it
+    EnPassant,
+    ///The king castles towards the side, the rook jumps over it
+    Castle(CastlingSide),
+    ///No piece moves, the turn passes to the other player
+    Null,
+}
+
+///Move
+/// A single move by a player, described by where the piece moves from and to,
+/// which piece a pawn is promoted to and what kind of move it is.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct Move {
+    pub from: BoardPosition,
+    pub to: BoardPosition,
+    pub promotion: Option<PieceKind>,
+    pub kind: MoveKind,
+}
+
+impl Move {
+    pub fn new(
+        from: BoardPosition,
+        to: BoardPosition,
+        promotion: Option<PieceKind>,
+        kind: MoveKind,
+    ) -> Self {
+        Move {
+            from,
+            to,
+            promotion,
+            kind,
+        }
+    }
+
+    /// Returns true if the move captures a piece, en passant included
+    pub fn is_capture(&self) -> bool {
+        matches!(self.kind, MoveKind::Capture | MoveKind::EnPassant)
+    }
+
+    /// Returns the null move, which passes the turn without moving a piece.
+    /// It is not a legal move in a game, but engines use it to search and to say they have no move.
+    pub fn null() -> Self {
+        let a1 = BoardPosition::new(File::A, Rank::One);
+        Move::new(a1, a1, None, MoveKind::Null)
+    }
+
+    /// Returns true if the move is the null move
+    pub fn is_null(&self) -> bool {
+        self.kind == MoveKind::Null
+    }
+
+    /// Returns true if the move is castling
+    pub fn is_castle(&self) -> bool {
+        matches!(self.kind, MoveKind::Castle(_))
+    }
+
+    /// Returns true if the move promotes a pawn
+    pub fn is_promotion(&self) -> bool {
+        self.promotion.is_some()
+    }
+}
+
+/// Writes the move in UCI long algebraic notation, the squares the piece moves from and to
+/// followed by the promotion, like `e2e4`, `e7e8q` or `e1g1` for castling. The null move is written `0000`.
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_null() {
+            return write!(f, "0000");
+        }
+        write!(f, "{}{}", self.from, self.to)?;
+        if let Some(promotion) = self.promotion {
+            let letter = promotion.with_color(Color::Black).char();
+            write!(f, "{}", letter)?;
+        }
+        Ok(())
+    }
+}
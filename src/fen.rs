@@ -0,0 +1,276 @@
+use crate::*;
+
+///FenField
+/// The six space separated fields of a FEN string, in the order they are written.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum FenField {
+    ///Where the pieces stand, rank eight first
+    PiecePlacement,
+    ///`w` or `b`
+    SideToMove,
+    ///`-` or some of `KQkq`
+    CastlingRights,
+    ///`-` or the square a pawn just passed over
+    EnPassant,
+    ///Halfmoves since the last pawn move or capture
+    HalfmoveClock,
+    ///The number of the current full move, starting at 1
+    FullmoveNumber,
+}
+
+///FenError
+/// Why a FEN string could not be read.
+#[derive(Debug, PartialEq, Eq)]
+pub enum FenError {
+    ///The string does not have exactly six fields, holds the number of fields found
+    WrongFieldCount(usize),
+    ///A character that does not belong in the field
+    InvalidCharacter { field: FenField, character: char },
+    ///The piece placement does not have eight ranks, holds the number of ranks found
+    WrongRankCount(usize),
+    ///A rank in the piece placement does not describe exactly eight squares
+    WrongRankLength(Rank),
+    ///A field is missing characters
+    IncompleteField(FenField),
+    ///One of the clock fields holds a number too large for the game to use
+    NumberTooLarge(FenField),
+    ///The fields could be read, but they describe a position that is not allowed
+    InvalidPosition(ChessError),
+}
+
+impl Game {
+    /// Creates a game from a position written in Forsyth-Edwards Notation,
+    /// for example `rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1`.
+    /// Returns the game or a FEN error naming the field that could not be read.
+    pub fn from_fen(fen: &str) -> Result<Game, FenError> {
+        let fields: Vec<&str> = fen.split_whitespace().collect();
+        if fields.len() != 6 {
+            return Err(FenError::WrongFieldCount(fields.len()));
+        }
+
+        let board = parse_piece_placement(fields[0])?;
+        let side_to_move = match fields[1] {
+            "w" => Color::White,
+            "b" => Color::Black,
+            field => return Err(invalid_field(FenField::SideToMove, field, "wb")),
+        };
+        let castling_rights = parse_castling_rights(fields[2])?;
+        let en_passant = parse_en_passant(fields[3])?;
+        let halfmove_clock = parse_number(FenField::HalfmoveClock, fields[4])?;
+        let fullmove_number = parse_number(FenField::FullmoveNumber, fields[5])?;
+
+        Game::from_parts(
+            board,
+            side_to_move,
+            castling_rights,
+            en_passant,
+            halfmove_clock,
+            fullmove_number,
+        )
+        .map_err(|error| match error {
+            //The numbers could be read, but the game can not count that far
+            ChessError::InvalidHalfmoveClock => FenError::NumberTooLarge(FenField::HalfmoveClock),
+            ChessError::InvalidMoveNumber if fullmove_number > 0 => {
+                FenError::NumberTooLarge(FenField::FullmoveNumber)
+            }
+            error => FenError::InvalidPosition(error),
+        })
+    }
+
+    /// Returns the current position written in Forsyth-Edwards Notation.
+    /// While a pawn waits for its promotion, the position from before the pawn move is written,
+    /// since a pawn on the last rank can not be read back.
+    pub fn to_fen(&self) -> String {
+        let mut piece_array = self.board.piece_array;
+        if let Some((chess_move, captured)) = self.pending_promotion {
+            let from = Position::from(chess_move.from);
+            let to = Position::from(chess_move.to);
+            piece_array[BOARD_SIZE - 1 - from.y][from.x] = piece_array[BOARD_SIZE - 1 - to.y][to.x];
+            piece_array[BOARD_SIZE - 1 - to.y][to.x] = captured;
+        }
+
+        let mut ranks = vec![];
+        for rank in piece_array.iter() {
+            let mut rank_field = String::new();
+            let mut empty = 0;
+            for square in rank.iter() {
+                match square {
+                    Some(piece) => {
+                        if empty > 0 {
+                            rank_field.push_str(&empty.to_string());
+                            empty = 0;
+                        }
+                        rank_field.push(piece.char());
+                    }
+                    None => empty += 1,
+                }
+            }
+            if empty > 0 {
+                rank_field.push_str(&empty.to_string());
+            }
+            ranks.push(rank_field);
+        }
+
+        let side_to_move = match self.get_turn() {
+            Color::White => "w",
+            Color::Black => "b",
+        };
+
+        let castling_rights = self.get_castling_rights();
+        let mut castling = String::new();
+        for (has_right, character) in [
+            (castling_rights.white_kingside, 'K'),
+            (castling_rights.white_queenside, 'Q'),
+            (castling_rights.black_kingside, 'k'),
+            (castling_rights.black_queenside, 'q'),
+        ] {
+            if has_right {
+                castling.push(character);
+            }
+        }
+        if castling.is_empty() {
+            castling.push('-');
+        }
+
+        let en_passant = match self.get_en_passant() {
+            Some(square) => square.to_string(),
+            None => "-".to_string(),
+        };
+
+        format!(
+            "{} {} {} {} {} {}",
+            ranks.join("/"),
+            side_to_move,
+            castling,
+            en_passant,
+            self.get_halfmove_clock(),
+            self.get_fullmove_number()
+        )
+    }
+}
+
+/// Returns the error for a field that is not one of its allowed values.
+/// The failing character is the first one not in `allowed`, or the second one if the field is too long.
+fn invalid_field(field: FenField, value: &str, allowed: &str) -> FenError {
+    match value.chars().find(|c| !allowed.contains(*c)) {
+        Some(character) => FenError::InvalidCharacter { field, character },
+        None => match value.chars().nth(1) {
+            Some(character) => FenError::InvalidCharacter { field, character },
+            None => FenError::IncompleteField(field),
+        },
+    }
+}
+
+/// Reads the piece placement field onto a new board
+fn parse_piece_placement(field: &str) -> Result<Board, FenError> {
+    let ranks: Vec<&str> = field.split('/').collect();
+    if ranks.len() != BOARD_SIZE {
+        return Err(FenError::WrongRankCount(ranks.len()));
+    }
+
+    let mut board = Board::new();
+    for (i, rank_field) in ranks.iter().enumerate() {
+        let rank = Rank::try_from(BOARD_SIZE - 1 - i).unwrap();
+        let mut x = 0;
+        for character in rank_field.chars() {
+            if let Some(empty) = character.to_digit(10).filter(|d| (1..=8).contains(d)) {
+                x += empty as usize;
+            } else {
+                let piece = piece_from_char(character).ok_or(FenError::InvalidCharacter {
+                    field: FenField::PiecePlacement,
+                    character,
+                })?;
+                if x >= BOARD_SIZE {
+                    return Err(FenError::WrongRankLength(rank));
+                }
+                board.set_piece(piece, &Position::new(x, usize::from(rank)));
+                x += 1;
+            }
+        }
+        if x != BOARD_SIZE {
+            return Err(FenError::WrongRankLength(rank));
+        }
+    }
+    Ok(board)
+}
+
+/// Returns the piece written as `character`, uppercase for white and lowercase for black
+fn piece_from_char(character: char) -> Option<Piece> {
+    let color = if character.is_ascii_uppercase() {
+        Color::White
+    } else {
+        Color::Black
+    };
+    let kind = match character.to_ascii_lowercase() {
+        'p' => PieceKind::Pawn,
+        'n' => PieceKind::Knight,
+        'b' => PieceKind::Bishop,
+        'r' => PieceKind::Rook,
+        'q' => PieceKind::Queen,
+        'k' => PieceKind::King,
+        _ => return None,
+    };
+    Some(kind.with_color(color))
+}
+
+/// Reads the castling rights field
+fn parse_castling_rights(field: &str) -> Result<CastlingRights, FenError> {
+    let mut castling_rights = CastlingRights::none();
+    if field == "-" {
+        return Ok(castling_rights);
+    }
+    for character in field.chars() {
+        match character {
+            'K' => castling_rights.white_kingside = true,
+            'Q' => castling_rights.white_queenside = true,
+            'k' => castling_rights.black_kingside = true,
+            'q' => castling_rights.black_queenside = true,
+            _ => {
+                return Err(FenError::InvalidCharacter {
+                    field: FenField::CastlingRights,
+                    character,
+                })
+            }
+        }
+    }
+    Ok(castling_rights)
+}
+
+/// Reads the en passant field, a lowercase square like `e3`
+fn parse_en_passant(field: &str) -> Result<Option<BoardPosition>, FenError> {
+    if field == "-" {
+        return Ok(None);
+    }
+    let mut chars = field.chars();
+    let invalid = |character| FenError::InvalidCharacter {
+        field: FenField::EnPassant,
+        character,
+    };
+
+    let file = chars
+        .next()
+        .ok_or(FenError::IncompleteField(FenField::EnPassant))?;
+    let file = match file {
+        'a'..='h' => File::try_from(file.to_ascii_uppercase()).unwrap(),
+        _ => return Err(invalid(file)),
+    };
+    let rank = chars
+        .next()
+        .ok_or(FenError::IncompleteField(FenField::EnPassant))?;
+    let rank = Rank::try_from(rank).map_err(|_| invalid(rank))?;
+    if let Some(character) = chars.next() {
+        return Err(invalid(character));
+    }
+    Ok(Some(BoardPosition::new(file, rank)))
+}
+
+/// Reads one of the clock fields
+fn parse_number(field: FenField, value: &str) -> Result<usize, FenError> {
+    if let Some(character) = value.chars().find(|c| !c.is_ascii_digit()) {
+        return Err(FenError::InvalidCharacter { field, character });
+    }
+    if value.is_empty() {
+        return Err(FenError::IncompleteField(field));
+    }
+    value.parse().map_err(|_| FenError::NumberTooLarge(field))
+}
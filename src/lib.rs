@@ -5,6 +5,15 @@ use position::*;
 pub mod board;
 pub mod moveset;
 use board::*;
+pub mod castling;
+use castling::*;
+pub mod outcome;
+use outcome::*;
+pub mod chess_move;
+use chess_move::*;
+pub mod fen;
+pub mod san;
+pub mod uci;
 
 #[derive(Copy, Clone, Debug, PartialEq, Hash, Eq)]
 pub enum GameState {
@@ -12,11 +21,37 @@ pub enum GameState {
     InProgress,
     ///Game is in check, the next player must make a move to get out of check
     Check,
-    ///Game is over, the color is the winner
-    GameOver(Color),
+    ///Game is over by checkmate, the color is the winner
+    Checkmate(Color),
+    ///Game is over in a draw, the next player has no legal moves but is not in check
+    Stalemate,
+    ///Game is over in a draw, the reason explains which rule ended it
+    Draw(DrawReason),
+    ///Game is over by resignation, the color is the player who resigned
+    Resigned(Color),
+    ///Game is over on time, the color is the player who ran out of time
+    TimedOut(Color),
     ///Game is in promotion, the next player must promote a pawn to proceed into another game state
     Promotion(BoardPosition),
 }
+#[derive(Copy, Clone, Debug, PartialEq, Hash, Eq)]
+pub enum DrawReason {
+    ///The same position occurred three times and the player to move claimed the draw
+    ThreefoldRepetition,
+    ///The same position occurred five times, this ends the game without a claim
+    FivefoldRepetition,
+    ///Fifty moves by each player passed without a pawn move or capture and the player to move claimed the draw
+    FiftyMoveRule,
+    ///Seventy-five moves by each player passed without a pawn move or capture, this ends the game without a claim
+    SeventyFiveMoveRule,
+    ///Neither player has enough pieces left to ever checkmate
+    InsufficientMaterial,
+    ///The players agreed to a draw
+    Agreement,
+    ///A player ran out of time, but the other player cannot checkmate
+    Timeout,
+}
+
 #[derive(Debug, PartialEq, Copy, Clone, Hash, Eq)]
 pub enum Color {
     White,
@@ -25,12 +60,28 @@ pub enum Color {
 
 impl Color {
     /// Returns the other color
-    fn other(&self) -> Self {
+    pub fn other(&self) -> Self {
         match self {
             Self::Black => Self::White,
             Self::White => Self::Black,
         }
     }
+
+    /// Returns the rank the king and the other big pieces of the color start on
+    fn back_rank(&self) -> Rank {
+        match self {
+            Self::White => Rank::One,
+            Self::Black => Rank::Eight,
+        }
+    }
+
+    /// Returns the rank the pawns of the color start on
+    fn pawn_start_rank(&self) -> Rank {
+        match self {
+            Self::White => Rank::Two,
+            Self::Black => Rank::Seven,
+        }
+    }
 }
 
 #[derive(Debug, PartialEq, Clone, Copy, Eq, Hash)]
@@ -73,12 +124,69 @@ impl Piece {
             | Rook(color) => *color,
         }
     }
+
+    ///Returns the kind of the piece, without its color
+    pub fn kind(&self) -> PieceKind {
+        match self {
+            Pawn(_) => PieceKind::Pawn,
+            Knight(_) => PieceKind::Knight,
+            King(_) => PieceKind::King,
+            Queen(_) => PieceKind::Queen,
+            Bishop(_) => PieceKind::Bishop,
+            Rook(_) => PieceKind::Rook,
+        }
+    }
+}
+
+///PieceKind
+/// The kind of a piece without its color, used where the color is already known, such as promotions.
+#[derive(Debug, PartialEq, Clone, Copy, Eq, Hash)]
+pub enum PieceKind {
+    Pawn,
+    Knight,
+    King,
+    Queen,
+    Bishop,
+    Rook,
+}
+
+impl PieceKind {
+    /// The kinds a pawn can be promoted to, best first
+    pub const PROMOTIONS: [PieceKind; 4] = [
+        PieceKind::Queen,
+        PieceKind::Rook,
+        PieceKind::Bishop,
+        PieceKind::Knight,
+    ];
+
+    ///Returns the piece of this kind with the given color
+    pub fn with_color(&self, color: Color) -> Piece {
+        match self {
+            PieceKind::Pawn => Pawn(color),
+            PieceKind::Knight => Knight(color),
+            PieceKind::King => King(color),
+            PieceKind::Queen => Queen(color),
+            PieceKind::Bishop => Bishop(color),
+            PieceKind::Rook => Rook(color),
+        }
+    }
+}
+
+///PromotionPolicy
+/// What happens when a pawn reaches the last rank without a promotion piece being chosen.
+#[derive(Debug, PartialEq, Clone, Copy, Eq, Hash, Default)]
+pub enum PromotionPolicy {
+    ///The game waits in `GameState::Promotion` until `promote_pawn` is called
+    #[default]
+    Ask,
+    ///The pawn is promoted to a queen right away
+    AutoQueen,
 }
 
 #[derive(Debug, PartialEq, Eq)]
 pub enum ChessError {
     /// Occurs when trying to move a piece to an illegal position
-    IllegalMove,
+    IllegalMove(IllegalMoveReason),
     /// Occurs when trying to move a piece when the game is over
     GameAlreadyOver,
     /// Occurs when trying to spawn a piece on top of another piece
@@ -98,7 +206,80 @@ pub enum ChessError {
     /// Occurs when a pawn needs to be promoted before the game can continue
     PromoteFirst,
     /// Occurs when trying to move a piece that is not the current players
-    NotYourTurn
+    NotYourTurn,
+    /// Occurs when claiming a draw that the rules do not allow yet
+    NoDrawToClaim,
+    /// Occurs when accepting or declining a draw that the other player has not offered
+    NoDrawOffer,
+    /// Occurs when creating a game from a position that could never happen, see `Board::validate`
+    InvalidPosition,
+    /// Occurs when creating a game with castling rights for a king or rook that is not on its starting square
+    InvalidCastlingRights,
+    /// Occurs when creating a game with an en passant square no pawn can have passed over
+    InvalidEnPassant,
+    /// Occurs when creating a game with a fullmove number of zero, or one too large to count the moves with
+    InvalidMoveNumber,
+    /// Occurs when creating a game with a halfmove clock past the seventy-five-move rule
+    InvalidHalfmoveClock,
+    /// Occurs when undoing a move before any move has been made
+    NothingToUndo,
+    /// Occurs when redoing a move without any undone moves left
+    NothingToRedo,
+}
+
+/// IllegalMoveReason
+/// Why a move is not allowed.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum IllegalMoveReason {
+    /// The piece can never move like that, not even on an empty board
+    NotInPattern,
+    /// Another piece stands in the way, or the destination holds a piece that cannot be captured
+    PathBlocked,
+    /// A pawn can only move diagonally when it captures a piece
+    NothingToCapture,
+    /// The move would leave the players own king in check
+    LeavesKingInCheck,
+    /// The king or the rook has already moved, or the rook is gone
+    NoCastlingRights,
+    /// The king may not castle while it is in check
+    CastlingOutOfCheck,
+    /// The king may not castle over a square that is attacked
+    CastlingThroughCheck,
+}
+
+/// Pin
+/// A piece that cannot leave the line between its king and an enemy rook, bishop or queen without exposing the king.
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub struct Pin {
+    /// The position of the pinned piece
+    pub pinned: BoardPosition,
+    /// The position of the enemy piece pinning it
+    pub pinner: BoardPosition,
+    /// Every position between the king and the pinner, including the pinner.
+    /// The pinned piece can only move along these positions.
+    pub ray: Vec<BoardPosition>,
+}
+
+/// UndoInfo
+/// Everything needed to take back a move made with `Game::make_move`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct UndoInfo {
+    chess_move: Move,
+    piece: Option<Piece>,
+    captured: Option<(BoardPosition, Piece)>,
+    castling_rights: CastlingRights,
+    en_passant: Option<BoardPosition>,
+    halfmove_clock: usize,
+    state: GameState,
+    draw_offer: Option<Color>,
+    position_history_len: usize,
+}
+
+impl UndoInfo {
+    /// Returns the move that can be taken back
+    pub fn get_move(&self) -> Move {
+        self.chess_move
+    }
 }
 
 /// Game
@@ -106,6 +287,31 @@ pub struct Game {
     state: GameState,
     pub board: Board,
     moves_made: usize,
+    en_passant: Option<BoardPosition>,
+    castling_rights: CastlingRights,
+    position_history: Vec<PositionKey>,
+    halfmove_clock: usize,
+    draw_offer: Option<Color>,
+    pending_promotion: Option<(Move, Option<Piece>)>,
+    promotion_policy: PromotionPolicy,
+    move_history: Vec<UndoInfo>,
+    redo_moves: Vec<Move>,
+}
+
+/// PositionKey
+/// The parts of a position that decide if two positions are the same for the repetition rules.
+#[derive(PartialEq, Eq, Hash, Clone)]
+struct PositionKey {
+    piece_array: [[Option<Piece>; BOARD_SIZE]; BOARD_SIZE],
+    turn: Color,
+    castling_rights: CastlingRights,
+    en_passant: Option<BoardPosition>,
+}
+
+impl Default for Game {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Game {
@@ -123,11 +329,94 @@ impl Game {
             state: GameState::InProgress,
             board: Board::new(),
             moves_made: 0,
+            en_passant: None,
+            castling_rights: CastlingRights::none(),
+            position_history: vec![],
+            halfmove_clock: 0,
+            draw_offer: None,
+            pending_promotion: None,
+            promotion_policy: PromotionPolicy::default(),
+            move_history: vec![],
+            redo_moves: vec![],
+        }
+    }
+
+    /// Creates a game from a position, with the player to move, the castling rights,
+    /// the en passant square, the halfmove clock and the number of the current full move.
+    /// The game state is computed from the position, it can already be over.
+    /// Returns the game or a chess error explaining what is wrong with the parts.
+    pub fn from_parts(
+        board: Board,
+        side_to_move: Color,
+        castling_rights: CastlingRights,
+        en_passant: Option<BoardPosition>,
+        halfmove_clock: usize,
+        fullmove_number: usize,
+    ) -> Result<Game, ChessError> {
+        //The move count has to leave room for the next move to be made
+        let moves_made = fullmove_number
+            .checked_sub(1)
+            .and_then(|moves| moves.checked_mul(2))
+            .and_then(|moves| match side_to_move {
+                Color::White => Some(moves),
+                Color::Black => moves.checked_add(1),
+            })
+            .filter(|moves| *moves < usize::MAX)
+            .ok_or(ChessError::InvalidMoveNumber)?;
+        //The game is drawn once the clock reaches the seventy-five-move rule, it never counts further
+        if halfmove_clock > 150 {
+            return Err(ChessError::InvalidHalfmoveClock);
+        }
+
+        let mut game = Game::empty();
+        game.board = board;
+        game.moves_made = moves_made;
+        game.castling_rights = castling_rights;
+        game.en_passant = en_passant;
+        game.halfmove_clock = halfmove_clock;
+
+        if !game.board.validate(side_to_move).is_empty() {
+            return Err(ChessError::InvalidPosition);
+        }
+
+        //Castling rights need the king and the rook on their starting squares
+        for color in [Color::White, Color::Black] {
+            for side in [CastlingSide::Kingside, CastlingSide::Queenside] {
+                let king_position = BoardPosition::new(File::E, color.back_rank());
+                let rook_position = BoardPosition::new(side.rook_file(), color.back_rank());
+                if castling_rights.has(color, side)
+                    && (game.get_piece(&king_position) != Some(King(color))
+                        || game.get_piece(&rook_position) != Some(Rook(color)))
+                {
+                    return Err(ChessError::InvalidCastlingRights);
+                }
+            }
+        }
+
+        //The en passant square is the empty square a pawn of the other player just double-stepped over
+        if let Some(target) = en_passant {
+            let color = side_to_move.other();
+            let start = BoardPosition::new(target.file, color.pawn_start_rank());
+            let passed = moveset::Move::Forward(color).get_position(&start.into(), 1);
+            let landed = moveset::Move::Forward(color).get_position(&start.into(), 2);
+            if passed != Some(target.into())
+                || game.get_piece(&start).is_some()
+                || game.get_piece(&target).is_some()
+                || landed.and_then(|p| game.board.get_piece(&p)) != Some(Pawn(color))
+            {
+                return Err(ChessError::InvalidEnPassant);
+            }
         }
+
+        game.post_move_probe();
+        Ok(game)
     }
 
     /// Initialises the game by placing all pieces on the board in the default chess starting positions.
+    /// Both players get their castling rights back.
     pub fn init(&mut self) {
+        self.castling_rights = CastlingRights::all();
+
         // Vita pjäser
         let color = Color::Black;
         let mut position = Position::new(0, 7);
@@ -195,14 +484,27 @@ impl Game {
     }
 
     /// Moves the piece from the `from` position to the `to` position on the board.
+    /// A pawn reaching the last rank is promoted according to the promotion policy.
     /// Returns the new game state or a chess error
     pub fn move_piece(
         &mut self,
         from: &BoardPosition,
         to: &BoardPosition,
     ) -> Result<GameState, ChessError> {
-        self.pre_move_probe();
-        if let GameState::GameOver(_) = self.get_game_state() {
+        self.move_piece_with_promotion(from, to, None)
+    }
+
+    /// Moves the piece from the `from` position to the `to` position on the board,
+    /// promoting a pawn that reaches the last rank to the given kind of piece.
+    /// Without a promotion the promotion policy decides what happens to the pawn.
+    /// Returns the new game state or a chess error
+    pub fn move_piece_with_promotion(
+        &mut self,
+        from: &BoardPosition,
+        to: &BoardPosition,
+        promotion: Option<PieceKind>,
+    ) -> Result<GameState, ChessError> {
+        if self.is_game_over() {
             return Err(ChessError::GameAlreadyOver);
         }
         if let GameState::Promotion(_) = self.get_game_state() {
@@ -217,96 +519,464 @@ impl Game {
             }
         }
 
-        //Get the possible moves for the piece
-        if let Some(possible_moves) = self.get_possible_moves(from) {
-            //Check if the move is in the possible moves
-            if possible_moves.contains(to) {
-                //Move the piece
-                self.board.move_piece(&from.into(), &to.into());
+        //Check if the move is in the possible moves for the piece
+        if !self
+            .get_possible_moves(from)
+            .is_some_and(|moves| moves.contains(to))
+        {
+            return Err(ChessError::IllegalMove(
+                self.get_illegal_move_reason(from, to),
+            ));
+        }
+
+        let piece = self.get_piece(from).unwrap();
+        let is_promotion = Self::is_promotion_move(piece, to);
+        //Only pawns reaching the last rank can be promoted, and never to a pawn or king
+        if let Some(kind) = promotion {
+            if !is_promotion || !PieceKind::PROMOTIONS.contains(&kind) {
+                return Err(ChessError::PromotionError);
+            }
+        }
+        let promotion = match (promotion, self.promotion_policy) {
+            (None, PromotionPolicy::AutoQueen) if is_promotion => Some(PieceKind::Queen),
+            _ => promotion,
+        };
+
+        let kind = self.get_move_kind(piece, from, to);
+        let chess_move = Move::new(*from, *to, promotion, kind);
+
+        //A new move replaces the moves that were undone
+        self.redo_moves.clear();
+
+        if is_promotion && promotion.is_none() {
+            return Ok(self.wait_for_promotion(chess_move));
+        }
+
+        Ok(self.play_move(chess_move))
+    }
+
+    /// Moves the pawn to the last rank where it waits until the player chooses what it becomes.
+    /// Returns the new game state
+    fn wait_for_promotion(&mut self, chess_move: Move) -> GameState {
+        let captured = self.board.take_piece(&chess_move.to.into());
+        self.board
+            .move_piece(&chess_move.from.into(), &chess_move.to.into());
+        self.pending_promotion = Some((chess_move, captured));
+        self.state = GameState::Promotion(chess_move.to);
+        self.get_game_state()
+    }
+
+    /// Makes a legal move, remembers it so it can be undone and updates the game state.
+    /// Returns the new game state
+    fn play_move(&mut self, chess_move: Move) -> GameState {
+        //Remember the position before the move for the repetition rules
+        let position_key = self.get_position_key();
+        let undo = self.make_move(chess_move);
+        self.position_history.push(position_key);
+        self.move_history.push(undo);
 
-                if self.is_promotion_available_at(to) {
-                    self.state = GameState::Promotion(to.clone());
-                    return Ok(self.state);
+        //Making a move declines the draw offered by the other player
+        if self.draw_offer == Some(self.get_turn()) {
+            self.draw_offer = None;
+        }
+
+        self.post_move_probe()
+    }
+
+    /// Takes back the last move, or the pawn move waiting for a promotion.
+    /// The game is exactly as it was before the move, and the move can be made again with `redo`.
+    /// A game that ended by a move, like checkmate, is reopened by taking back that move.
+    /// A game that ended without a move, by resignation, timeout, an agreed or a claimed draw, stays over.
+    /// Returns the new game state or a chess error if there is no move to take back.
+    pub fn undo(&mut self) -> Result<GameState, ChessError> {
+        if self.is_ended_without_move() {
+            return Err(ChessError::GameAlreadyOver);
+        }
+        if let Some(chess_move) = self.take_back_pending_promotion() {
+            self.redo_moves.push(chess_move);
+            return Ok(self.get_game_state());
+        }
+        let undo = self.move_history.pop().ok_or(ChessError::NothingToUndo)?;
+        self.redo_moves.push(undo.get_move());
+        self.unmake_move(undo);
+        Ok(self.get_game_state())
+    }
+
+    /// Makes the last undone move again.
+    /// Returns the new game state or a chess error if there is no undone move or the game is over.
+    pub fn redo(&mut self) -> Result<GameState, ChessError> {
+        if self.is_game_over() {
+            return Err(ChessError::GameAlreadyOver);
+        }
+        let chess_move = self.redo_moves.pop().ok_or(ChessError::NothingToRedo)?;
+        //An undone pawn move that was waiting for a promotion waits again
+        let piece = self.get_piece(&chess_move.from).unwrap();
+        if chess_move.promotion.is_none() && Self::is_promotion_move(piece, &chess_move.to) {
+            return Ok(self.wait_for_promotion(chess_move));
+        }
+        Ok(self.play_move(chess_move))
+    }
+
+    /// Ends the game without a move, the undone moves can no longer be made again.
+    fn end_game(&mut self, state: GameState) -> GameState {
+        self.redo_moves.clear();
+        self.state = state;
+        self.get_game_state()
+    }
+
+    /// Returns true if the game ended by resignation, timeout, an agreed or a claimed draw instead of by a move
+    fn is_ended_without_move(&self) -> bool {
+        matches!(
+            self.state,
+            GameState::Resigned(_)
+                | GameState::TimedOut(_)
+                | GameState::Draw(
+                    DrawReason::Agreement
+                        | DrawReason::Timeout
+                        | DrawReason::ThreefoldRepetition
+                        | DrawReason::FiftyMoveRule
+                )
+        )
+    }
+
+    /// Returns the moves made in the game so far, oldest first.
+    pub fn get_move_history(&self) -> Vec<Move> {
+        self.move_history
+            .iter()
+            .map(|undo| undo.get_move())
+            .collect()
+    }
+
+    /// Sets what happens when a pawn reaches the last rank without a promotion piece being chosen.
+    pub fn set_promotion_policy(&mut self, policy: PromotionPolicy) {
+        self.promotion_policy = policy;
+    }
+
+    /// Returns what happens when a pawn reaches the last rank without a promotion piece being chosen.
+    pub fn get_promotion_policy(&self) -> PromotionPolicy {
+        self.promotion_policy
+    }
+
+    /// Makes the move on the board without checking if it is legal and without updating the game state.
+    /// The move should be one of the legal moves, a pawn moving to the last rank should have a promotion.
+    /// The null move from `Move::null()` can be made as well, it passes the turn without moving a piece.
+    /// Returns the information needed to take the move back with `unmake_move`.
+    pub fn make_move(&mut self, chess_move: Move) -> UndoInfo {
+        let from = chess_move.from;
+        let to = chess_move.to;
+        let piece = match chess_move.kind {
+            MoveKind::Null => None,
+            _ => Some(self.get_piece(&from).expect("There is no piece to move")),
+        };
+        let mut undo = UndoInfo {
+            chess_move,
+            piece,
+            captured: None,
+            castling_rights: self.castling_rights,
+            en_passant: self.en_passant,
+            halfmove_clock: self.halfmove_clock,
+            state: self.state,
+            draw_offer: self.draw_offer,
+            position_history_len: self.position_history.len(),
+        };
+
+        //A null move only passes the turn to the other player
+        let Some(piece) = piece else {
+            self.en_passant = None;
+            self.halfmove_clock += 1;
+            self.moves_made += 1;
+            return undo;
+        };
+
+        //The captured piece stands on the destination square, except when capturing en passant
+        let captured_position = match chess_move.kind {
+            MoveKind::EnPassant => BoardPosition::new(to.file, from.rank),
+            _ => to,
+        };
+        undo.captured = self
+            .board
+            .take_piece(&captured_position.into())
+            .map(|captured| (captured_position, captured));
+
+        //Pawn moves and captures reset the halfmove clock
+        if matches!(piece, Pawn(_)) || undo.captured.is_some() {
+            self.halfmove_clock = 0;
+        } else {
+            self.halfmove_clock += 1;
+        }
+
+        //Remember the en passant target before the pawn leaves its square
+        self.en_passant = self.get_en_passant_target(&from, &to);
+
+        //Move the piece
+        self.board.move_piece(&from.into(), &to.into());
+        if let Some(promotion) = chess_move.promotion {
+            self.board
+                .set_piece(promotion.with_color(piece.get_color()), &to.into());
+        }
+        //Castling moves the rook to the other side of the king
+        if let MoveKind::Castle(side) = chess_move.kind {
+            self.board.move_piece(
+                &BoardPosition::new(side.rook_file(), from.rank).into(),
+                &BoardPosition::new(side.rook_destination_file(), from.rank).into(),
+            );
+        }
+        self.update_castling_rights(&from, &to);
+
+        //Change the turn
+        self.moves_made += 1;
+        undo
+    }
+
+    /// Takes back a move made with `make_move`, the game is exactly as it was before the move.
+    /// Moves have to be taken back in the opposite order they were made.
+    pub fn unmake_move(&mut self, undo: UndoInfo) {
+        let from = undo.chess_move.from;
+        let to = undo.chess_move.to;
+
+        if let MoveKind::Castle(side) = undo.chess_move.kind {
+            self.board.move_piece(
+                &BoardPosition::new(side.rook_destination_file(), from.rank).into(),
+                &BoardPosition::new(side.rook_file(), from.rank).into(),
+            );
+        }
+        //Put back the piece as it was, a promoted pawn becomes a pawn again
+        if let Some(piece) = undo.piece {
+            self.board.despawn_piece(&to.into());
+            self.board.set_piece(piece, &from.into());
+        }
+        if let Some((position, captured)) = undo.captured {
+            self.board.set_piece(captured, &position.into());
+        }
+
+        self.castling_rights = undo.castling_rights;
+        self.en_passant = undo.en_passant;
+        self.halfmove_clock = undo.halfmove_clock;
+        self.state = undo.state;
+        self.draw_offer = undo.draw_offer;
+        self.position_history.truncate(undo.position_history_len);
+        self.moves_made -= 1;
+    }
+
+    /// Returns the position of the pawn that is captured en passant if `piece` moves from `from` to `to`.
+    /// Returns None if the move is not an en passant capture.
+    fn get_en_passant_capture(
+        &self,
+        piece: Piece,
+        from: &BoardPosition,
+        to: &BoardPosition,
+    ) -> Option<BoardPosition> {
+        match piece {
+            Pawn(_) if self.en_passant == Some(*to) && from.file != to.file => {
+                Some(BoardPosition::new(to.file, from.rank))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the square a pawn passes over if the piece at `from` double-steps to `to`.
+    /// This is the square an enemy pawn can capture en passant on the next move.
+    fn get_en_passant_target(
+        &self,
+        from: &BoardPosition,
+        to: &BoardPosition,
+    ) -> Option<BoardPosition> {
+        match self.get_piece(from) {
+            Some(Pawn(color)) if from.rank == color.pawn_start_rank() && from.file == to.file => {
+                let passed = moveset::Move::Forward(color).get_position(&from.into(), 1)?;
+                let double_step = moveset::Move::Forward(color).get_position(&from.into(), 2)?;
+                if Position::from(to) == double_step {
+                    passed.try_into().ok()
+                } else {
+                    None
                 }
+            }
+            _ => None,
+        }
+    }
 
-                return Ok(self.post_move_probe());
-            } else {
-                return Err(ChessError::IllegalMove);
+    /// Returns the castling side if `piece` moving from `from` to `to` is a castling move.
+    /// Returns None if the move is not castling.
+    fn get_castling_side(
+        piece: Piece,
+        from: &BoardPosition,
+        to: &BoardPosition,
+    ) -> Option<CastlingSide> {
+        match piece {
+            King(color) if *from == BoardPosition::new(File::E, color.back_rank()) => {
+                [CastlingSide::Kingside, CastlingSide::Queenside]
+                    .into_iter()
+                    .find(|side| *to == BoardPosition::new(side.king_destination_file(), from.rank))
             }
+            _ => None,
         }
+    }
 
-        Err(ChessError::IllegalMove)
+    /// Revokes the castling rights that are lost by a move from `from` to `to`.
+    /// Moving the king loses both rights, moving or capturing a rook loses the right on its side.
+    fn update_castling_rights(&mut self, from: &BoardPosition, to: &BoardPosition) {
+        for color in [Color::White, Color::Black] {
+            let king_position = BoardPosition::new(File::E, color.back_rank());
+            for side in [CastlingSide::Kingside, CastlingSide::Queenside] {
+                let rook_position = BoardPosition::new(side.rook_file(), color.back_rank());
+                if *from == king_position || *from == rook_position || *to == rook_position {
+                    self.castling_rights.revoke(color, side);
+                }
+            }
+        }
     }
 
-    fn is_won(&mut self) -> bool {
-        //Check for win
-        if self.board.black_king_position.is_none() || self.board.white_king_position.is_none() {
-            return true;
-        } else {
-            return false;
-        };
+    /// Returns the castling rights of both players.
+    pub fn get_castling_rights(&self) -> CastlingRights {
+        self.castling_rights
     }
 
-    /// Checks wether or not a position is available for promotion
-    fn is_promotion_available_at(&self, position: &BoardPosition) -> bool {
-        //Check if the moved piece is a pawn and can be promoted
-        if let Some(piece) = self.get_piece(position) {
-            if let Pawn(_) = piece {
-                match (position.rank, self.get_turn()) {
-                    (Rank::Eight, Color::White) => {
-                        return true;
-                    }
-                    (Rank::One, Color::Black) => {
-                        return true;
+    /// Returns the en passant target square, if the last move was a pawn double-step.
+    pub fn get_en_passant(&self) -> Option<BoardPosition> {
+        self.en_passant
+    }
+
+    /// Checks wether or not `piece` moving to `to` is a pawn reaching the last rank and has to be promoted
+    fn is_promotion_move(piece: Piece, to: &BoardPosition) -> bool {
+        match piece {
+            Pawn(Color::White) => to.rank == Rank::Eight,
+            Pawn(Color::Black) => to.rank == Rank::One,
+            _ => false,
+        }
+    }
+
+    /// Checks if the current player is in check
+    fn is_check(&self) -> bool {
+        self.is_in_check(self.get_turn())
+    }
+
+    /// Checks if the king of the given color is attacked, no matter who's turn it is.
+    /// A player without a king on the board can never be in check
+    pub fn is_in_check(&self, color: Color) -> bool {
+        match self.board.get_king_position(color) {
+            Some(king_position) => self.board.is_square_attacked(&king_position, color.other()),
+            None => false,
+        }
+    }
+
+    /// Returns every legal move for the player who's turn it is.
+    /// A pawn move onto the last rank is listed once for every piece the pawn can be promoted to.
+    pub fn legal_moves(&mut self) -> Vec<Move> {
+        if self.is_game_over() {
+            return vec![];
+        }
+        self.legal_moves_for(self.get_turn())
+    }
+
+    /// Returns every legal move for the player of the given color, as if it was their turn.
+    /// Only the player who's turn it is can capture en passant.
+    pub fn legal_moves_for(&mut self, color: Color) -> Vec<Move> {
+        let mut moves = vec![];
+        for position in self.board.get_positions_of_color(color) {
+            let from = BoardPosition::try_from(position).unwrap();
+            let piece = self.get_piece(&from).unwrap();
+            for to in self.get_possible_moves(&from).unwrap_or_default() {
+                let kind = self.get_move_kind(piece, &from, &to);
+                if Self::is_promotion_move(piece, &to) {
+                    for promotion in PieceKind::PROMOTIONS {
+                        moves.push(Move::new(from, to, Some(promotion), kind));
                     }
-                    _ => {}
+                } else {
+                    moves.push(Move::new(from, to, None, kind));
                 }
             }
         }
-        return false;
+        moves
     }
 
-    /// Checks if the current player is in check
-    fn is_check(&mut self) -> bool {
-        //Get the position of the king of the current player
-        let king_position = match self.get_turn() {
-            Color::White => self.board.white_king_position.unwrap(),
-            Color::Black => self.board.black_king_position.unwrap(),
-        };
+    /// Returns what kind of move it is for `piece` to move from `from` to `to`.
+    /// The move is expected to be possible for the piece.
+    fn get_move_kind(&self, piece: Piece, from: &BoardPosition, to: &BoardPosition) -> MoveKind {
+        if let Some(side) = Self::get_castling_side(piece, from, to) {
+            MoveKind::Castle(side)
+        } else if self.get_en_passant_capture(piece, from, to).is_some() {
+            MoveKind::EnPassant
+        } else if self.get_piece(to).is_some() {
+            MoveKind::Capture
+        } else if self.get_en_passant_target(from, to).is_some() {
+            MoveKind::DoubleStep
+        } else {
+            MoveKind::Quiet
+        }
+    }
 
-        let mut pieces_to_check = self.board.get_all_pieces_of_color(self.get_turn().other());
-        pieces_to_check.dedup();
+    /// Checks if the piece at `from` can legally move to `to`, no matter who's turn it is.
+    pub fn is_legal_move(&mut self, from: &BoardPosition, to: &BoardPosition) -> bool {
+        self.get_possible_moves(from)
+            .is_some_and(|moves| moves.contains(to))
+    }
 
-        let king_in_check_by = |piece: &Piece| {
-            let moves_for_piece = moveset::get_moveset(*piece);
+    /// Returns every position attacked by the pieces of the given color.
+    /// A square is attacked if a piece of the color could capture an enemy piece standing there.
+    pub fn attacked_squares(&self, color: Color) -> Vec<BoardPosition> {
+        let mut squares = vec![];
+        for y in 0..BOARD_SIZE {
+            for x in 0..BOARD_SIZE {
+                let position = Position::new(x, y);
+                if self.board.is_square_attacked(&position, color) {
+                    squares.push(position.try_into().unwrap());
+                }
+            }
+        }
+        squares
+    }
+
+    /// Returns the positions of the pieces giving check to the player who's turn it is.
+    /// The list is empty if the player is not in check.
+    pub fn checkers(&self) -> Vec<BoardPosition> {
+        match self.board.get_king_position(self.get_turn()) {
+            Some(king_position) => self
+                .board
+                .attackers_of(&king_position, self.get_turn().other())
+                .into_iter()
+                .map(|p| p.try_into().unwrap())
+                .collect(),
+            None => vec![],
+        }
+    }
 
-            for action in moves_for_piece.moves {
-                'step: for step in 1..=moves_for_piece.steps {
-                    let next_position = action.get_position(&king_position, step);
+    /// Returns the pieces of the given color that are pinned to their king.
+    pub fn pinned_pieces(&self, color: Color) -> Vec<Pin> {
+        let mut pins = vec![];
+        let king_position = match self.board.get_king_position(color) {
+            Some(position) => position,
+            None => return pins,
+        };
 
-                    //if next_position is Ok, get the piece on that position
-                    if let Some(next_step) = next_position {
-                        //Get the piece on this step
-                        if let Some(p) = self.board.get_piece(&next_step) {
-                            if p == *piece && p.get_color() == self.get_turn().other() {
-                                return true;
-                            } else {
-                                break 'step;
+        //Look along every line from the king for a friendly piece with an enemy slider behind it
+        for (slider, moveset) in [
+            (Rook(color.other()), moveset::get_moveset(Rook(color))),
+            (Bishop(color.other()), moveset::get_moveset(Bishop(color))),
+        ] {
+            for action in moveset.moves {
+                let mut pinned = None;
+                let mut ray = vec![];
+                for p in moveset::get_steps(&king_position, &action, moveset.steps) {
+                    ray.push(p.try_into().unwrap());
+                    match (self.board.get_piece(&p), pinned) {
+                        (None, _) => {}
+                        (Some(piece), None) if piece.get_color() == color => pinned = Some(p),
+                        (Some(piece), Some(pinned)) => {
+                            if piece == slider || piece == Queen(color.other()) {
+                                pins.push(Pin {
+                                    pinned: pinned.try_into().unwrap(),
+                                    pinner: p.try_into().unwrap(),
+                                    ray,
+                                });
                             }
+                            break;
                         }
-                    } else {
-                        break 'step;
+                        (Some(_), None) => break,
                     }
                 }
             }
-            return false;
-        };
-
-        if pieces_to_check.iter().any(|p| king_in_check_by(p)) {
-            return true;
-        } else {
-            return false;
         }
+        pins
     }
 
     /// Returns the color of the player who's turn it is
@@ -318,46 +988,210 @@ impl Game {
     }
 
     fn post_move_probe(&mut self) -> GameState {
-        //Change the turn
-        self.moves_made += 1;
+        //Check if the player put the other player in check
+        let in_check = self.is_check();
 
-        //Check if the game is won
-        if self.is_won() {
-            self.state = GameState::GameOver(match self.board.white_king_position {
-                Some(_) => Color::White,
-                None => Color::Black,
-            });
+        //Check if the player put the other player in checkmate or stalemate
+        if !self.has_legal_moves() {
+            self.state = if in_check {
+                GameState::Checkmate(self.get_turn().other())
+            } else {
+                GameState::Stalemate
+            };
             return self.get_game_state();
         }
 
-        //Check if the player put the other player in check
-        if self.is_check() {
+        //Check if any player can still checkmate
+        if self.board.is_insufficient_material() {
+            self.state = GameState::Draw(DrawReason::InsufficientMaterial);
+            return self.get_game_state();
+        }
+
+        //Check if the same position has occurred five times
+        if self.get_repetition_count() >= 5 {
+            self.state = GameState::Draw(DrawReason::FivefoldRepetition);
+            return self.get_game_state();
+        }
+
+        //Check if 75 moves by each player were made without a pawn move or capture
+        if self.halfmove_clock >= 150 {
+            self.state = GameState::Draw(DrawReason::SeventyFiveMoveRule);
+            return self.get_game_state();
+        }
+
+        if in_check {
             self.state = GameState::Check;
             return self.get_game_state();
         }
 
         self.state = GameState::InProgress;
-        return self.get_game_state();
+        self.get_game_state()
     }
 
-    fn pre_move_probe(&mut self) -> GameState {
-        //Check if the game is won
-        if self.is_won() {
-            self.state = GameState::GameOver(match self.board.white_king_position {
-                Some(_) => Color::White,
-                None => Color::Black,
-            });
-            return self.get_game_state();
+    /// Returns true if the player who's turn it is has at least one legal move
+    fn has_legal_moves(&mut self) -> bool {
+        for position in self.board.get_positions_of_color(self.get_turn()) {
+            let position = BoardPosition::try_from(position).unwrap();
+            if self
+                .get_possible_moves(&position)
+                .is_some_and(|moves| !moves.is_empty())
+            {
+                return true;
+            }
         }
+        false
+    }
+
+    /// Returns true if the game has ended and no more moves can be made
+    pub fn is_game_over(&self) -> bool {
+        self.get_outcome().is_some()
+    }
+
+    /// Returns the result of the game and the reason it ended.
+    /// Returns None if the game is not over yet.
+    pub fn get_outcome(&self) -> Option<GameOutcome> {
+        GameOutcome::from_state(self.state)
+    }
 
-        return self.get_game_state();
+    /// The player of the given color resigns and the other player wins.
+    /// Returns the new game state or a chess error if the game is already over.
+    pub fn resign(&mut self, color: Color) -> Result<GameState, ChessError> {
+        if self.is_game_over() {
+            return Err(ChessError::GameAlreadyOver);
+        }
+        Ok(self.end_game(GameState::Resigned(color)))
+    }
+
+    /// The player of the given color ran out of time and loses,
+    /// unless the other player cannot checkmate, then the game is a draw.
+    /// Returns the new game state or a chess error if the game is already over.
+    pub fn time_out(&mut self, color: Color) -> Result<GameState, ChessError> {
+        if self.is_game_over() {
+            return Err(ChessError::GameAlreadyOver);
+        }
+        let opponent_has_only_king = self
+            .board
+            .get_all_pieces_of_color(color.other())
+            .iter()
+            .all(|piece| matches!(piece, King(_)));
+
+        let state = if opponent_has_only_king || self.board.is_insufficient_material() {
+            GameState::Draw(DrawReason::Timeout)
+        } else {
+            GameState::TimedOut(color)
+        };
+        Ok(self.end_game(state))
+    }
+
+    /// The player of the given color offers a draw.
+    /// The offer stands until the other player accepts it, declines it or makes a move.
+    pub fn offer_draw(&mut self, color: Color) -> Result<(), ChessError> {
+        if self.is_game_over() {
+            return Err(ChessError::GameAlreadyOver);
+        }
+        self.draw_offer = Some(color);
+        Ok(())
+    }
+
+    /// The player of the given color accepts the draw offered by the other player.
+    /// Returns the new game state or a chess error if there is no offer to accept.
+    pub fn accept_draw(&mut self, color: Color) -> Result<GameState, ChessError> {
+        if self.is_game_over() {
+            return Err(ChessError::GameAlreadyOver);
+        }
+        if self.draw_offer != Some(color.other()) {
+            return Err(ChessError::NoDrawOffer);
+        }
+        self.draw_offer = None;
+        Ok(self.end_game(GameState::Draw(DrawReason::Agreement)))
+    }
+
+    /// The player of the given color declines the draw offered by the other player.
+    pub fn decline_draw(&mut self, color: Color) -> Result<(), ChessError> {
+        if self.draw_offer != Some(color.other()) {
+            return Err(ChessError::NoDrawOffer);
+        }
+        self.draw_offer = None;
+        Ok(())
+    }
+
+    /// Returns the color of the player with a standing draw offer, if any.
+    pub fn get_draw_offer(&self) -> Option<Color> {
+        self.draw_offer
+    }
+
+    /// Returns the key the current position is compared by for the repetition rules.
+    /// The en passant square only counts if the player to move can actually capture en passant.
+    fn get_position_key(&mut self) -> PositionKey {
+        let mut en_passant = None;
+        if let Some(target) = self.en_passant {
+            for position in self.board.get_positions_of_color(self.get_turn()) {
+                let position = BoardPosition::try_from(position).unwrap();
+                if matches!(self.get_piece(&position), Some(Pawn(_)))
+                    && self
+                        .get_possible_moves(&position)
+                        .is_some_and(|moves| moves.contains(&target))
+                {
+                    en_passant = Some(target);
+                }
+            }
+        }
+
+        PositionKey {
+            piece_array: self.board.piece_array,
+            turn: self.get_turn(),
+            castling_rights: self.castling_rights,
+            en_passant,
+        }
+    }
+
+    /// Returns how many times the current position has occurred in the game, including now.
+    pub fn get_repetition_count(&mut self) -> usize {
+        let position_key = self.get_position_key();
+        1 + self
+            .position_history
+            .iter()
+            .filter(|key| **key == position_key)
+            .count()
+    }
+
+    /// Returns the number of halfmoves made since the last pawn move or capture.
+    pub fn get_halfmove_clock(&self) -> usize {
+        self.halfmove_clock
+    }
+
+    /// Returns the number of the current full move, starting at 1 and increasing after black moves.
+    pub fn get_fullmove_number(&self) -> usize {
+        self.moves_made / 2 + 1
+    }
+
+    /// Claims a draw for the player to move.
+    /// The claim is accepted if the current position has occurred at least three times,
+    /// or if fifty moves by each player were made without a pawn move or capture.
+    /// Returns the new game state or a chess error explaining why the draw could not be claimed.
+    pub fn claim_draw(&mut self) -> Result<GameState, ChessError> {
+        if self.is_game_over() {
+            return Err(ChessError::GameAlreadyOver);
+        }
+        if let GameState::Promotion(_) = self.get_game_state() {
+            return Err(ChessError::PromoteFirst);
+        }
+
+        if self.get_repetition_count() >= 3 {
+            return Ok(self.end_game(GameState::Draw(DrawReason::ThreefoldRepetition)));
+        }
+        if self.halfmove_clock >= 100 {
+            return Ok(self.end_game(GameState::Draw(DrawReason::FiftyMoveRule)));
+        }
+
+        Err(ChessError::NoDrawToClaim)
     }
 
     /// Promotes the pawn at the game state promotions position to the new piece.
     /// Returns the new game state or a chess error explaining why the promotion failed.
     pub fn promote_pawn(&mut self, new_piece: Piece) -> Result<GameState, ChessError> {
         //Checks to see if the game state is promotion
-        if let GameState::Promotion(pawn_position) = self.state {
+        if let GameState::Promotion(_) = self.state {
             // Make sure the new piece is a legal promotion piece
             match new_piece {
                 Queen(_) | Rook(_) | Bishop(_) | Knight(_) => {}
@@ -365,13 +1199,32 @@ impl Game {
                     return Err(ChessError::PromotionError);
                 }
             }
-            //Promote the pawn
-            self.board.set_piece(new_piece, &pawn_position.into());
-            self.post_move_probe();
-            return Ok(self.get_game_state());
+            //Put the pawn back and make the move again with the chosen piece
+            let chess_move = self.take_back_pending_promotion().unwrap();
+            Ok(self.play_move(Move {
+                promotion: Some(new_piece.kind()),
+                ..chess_move
+            }))
         } else {
-            return Err(ChessError::PromotionError);
+            Err(ChessError::PromotionError)
+        }
+    }
+
+    /// Puts the pawn waiting for a promotion back where it came from.
+    /// Returns the pawn move, or None if no pawn is waiting for a promotion.
+    fn take_back_pending_promotion(&mut self) -> Option<Move> {
+        let (chess_move, captured) = self.pending_promotion.take()?;
+        self.board
+            .move_piece(&chess_move.to.into(), &chess_move.from.into());
+        if let Some(captured) = captured {
+            self.board.set_piece(captured, &chess_move.to.into());
         }
+        self.state = if self.is_check() {
+            GameState::Check
+        } else {
+            GameState::InProgress
+        };
+        Some(chess_move)
     }
 
     /// Get the current game state.
@@ -380,55 +1233,208 @@ impl Game {
     }
 
     /// Returns the possible moves for the piece at the given position.
-    /// If there is no piece at the given position, None is returned. TODO: Make possible_moves not depend on the current turn of the game
+    /// The moves are the same no matter who's turn it is, except that only the player to move can capture en passant.
+    /// If there is no piece at the given position, None is returned.
     pub fn get_possible_moves(&mut self, position: &BoardPosition) -> Option<Vec<BoardPosition>> {
         //Get the piece at the given position
-        if let Some(piece) = self.board.get_piece(&position.into()) {
-            //Get the moveset for that piece variant
-            let moveset = moveset::get_moveset(piece);
-            let mut legal_moves: Vec<BoardPosition> = vec![];
+        let piece = self.get_piece(position)?;
+        let mut legal_moves = self.get_pseudo_legal_moves(position, piece);
+
+        //Keep all moves that does not leave the players own king in check
+        legal_moves.retain(|to| !self.leaves_king_in_check(piece, position, to));
+        Some(legal_moves)
+    }
+
+    /// Returns the positions the piece at the given position can move to if its own king did not matter.
+    fn get_pseudo_legal_moves(&self, position: &BoardPosition, piece: Piece) -> Vec<BoardPosition> {
+        //Get the moveset for that piece variant
+        let moveset = moveset::get_moveset(piece);
+        let color = piece.get_color();
+        let mut moves: Vec<BoardPosition> = vec![];
 
+        if let Pawn(_) = piece {
+            moves.append(&mut self.get_pawn_moves(position, color));
+        } else {
+            if let King(_) = piece {
+                moves.append(&mut self.get_castling_moves(position, color));
+            }
             for move_action in moveset.moves {
                 for pos in moveset::get_steps(&position.into(), &move_action, moveset.steps) {
-                    if let Some(piece) = self.get_piece(&pos.try_into().unwrap()) {
-                        if piece.get_color() == self.get_turn() {
+                    if let Some(other) = self.get_piece(&pos.try_into().unwrap()) {
+                        //The king can never be captured
+                        if other.get_color() == color || matches!(other, King(_)) {
                             break;
                         } else {
-                            legal_moves.push(pos.try_into().unwrap());
+                            moves.push(pos.try_into().unwrap());
                             break;
                         }
                     } else {
-                        legal_moves.push(pos.try_into().unwrap());
+                        moves.push(pos.try_into().unwrap());
                     }
                 }
             }
+        }
+        moves
+    }
 
-            //START OF ERROR REGION
-            //Remove the current piece from the board
-            let current_piece = self.get_piece(position).unwrap();
-            self.board.despawn_piece(&position.into());
-            //Keep all moves that does not put current player in check
-            legal_moves.retain(|&x| {
-                // remove the piece from the new position
-                let other_piece = self.board.take_piece(&x.into());
-                //move the piece to the new position
-                self.board.set_piece(current_piece, &x.into());
-                //Check if the player is in check
-                let keep = !self.is_check();
-                //Remove the piece from the new position
-                self.board.despawn_piece(&x.into());
-                if other_piece.is_some() {
-                    self.board.set_piece(other_piece.unwrap(), &x.into());
-                }
-                keep
-            });
-            //Put the piece back on the board
-            self.board.set_piece(current_piece, &position.into());
-            //END
+    /// Checks if `piece` moving from `from` to `to` would leave the king of its own color in check
+    fn leaves_king_in_check(
+        &mut self,
+        piece: Piece,
+        from: &BoardPosition,
+        to: &BoardPosition,
+    ) -> bool {
+        let color = piece.get_color();
+        let kind = self.get_move_kind(piece, from, to);
+        let promotion = Self::is_promotion_move(piece, to).then_some(PieceKind::Queen);
+        let undo = self.make_move(Move::new(*from, *to, promotion, kind));
+        let in_check = self.is_in_check(color);
+        self.unmake_move(undo);
+        in_check
+    }
 
-            return Some(legal_moves);
+    /// Returns why the piece at `from` cannot move to `to`.
+    /// The move is expected to not be one of the possible moves for the piece.
+    fn get_illegal_move_reason(
+        &mut self,
+        from: &BoardPosition,
+        to: &BoardPosition,
+    ) -> IllegalMoveReason {
+        let piece = self.get_piece(from).unwrap();
+        let color = piece.get_color();
+
+        if let Some(side) = Self::get_castling_side(piece, from, to) {
+            let rook_position = BoardPosition::new(side.rook_file(), from.rank);
+            let passed = BoardPosition::new(side.rook_destination_file(), from.rank);
+            return if !self.castling_rights.has(color, side)
+                || self.get_piece(&rook_position) != Some(Rook(color))
+            {
+                IllegalMoveReason::NoCastlingRights
+            } else if side.files_between().iter().any(|file| {
+                self.get_piece(&BoardPosition::new(*file, from.rank))
+                    .is_some()
+            }) {
+                IllegalMoveReason::PathBlocked
+            } else if self.is_in_check(color) {
+                IllegalMoveReason::CastlingOutOfCheck
+            } else if self.board.is_square_attacked(&passed.into(), color.other()) {
+                IllegalMoveReason::CastlingThroughCheck
+            } else {
+                IllegalMoveReason::LeavesKingInCheck
+            };
+        }
+
+        if self.get_pseudo_legal_moves(from, piece).contains(to) {
+            return IllegalMoveReason::LeavesKingInCheck;
+        }
+
+        //The move is in the pattern of the piece if it could make it on an otherwise empty board
+        //Pawns move forward but only capture diagonally forward
+        let (moves, steps, captures) = match piece {
+            Pawn(_) => {
+                let steps = if from.rank == color.pawn_start_rank() {
+                    2
+                } else {
+                    1
+                };
+                let captures = vec![
+                    moveset::Move::ForwardLeft(color),
+                    moveset::Move::ForwardRight(color),
+                ];
+                (vec![moveset::Move::Forward(color)], steps, captures)
+            }
+            _ => {
+                let moveset = moveset::get_moveset(piece);
+                (moveset.moves, moveset.steps, vec![])
+            }
+        };
+        let reaches = |moves: &Vec<moveset::Move>, steps: usize| {
+            moves
+                .iter()
+                .any(|action| moveset::get_steps(&from.into(), action, steps).contains(&to.into()))
+        };
+
+        if reaches(&moves, steps) {
+            IllegalMoveReason::PathBlocked
+        } else if reaches(&captures, 1) && self.get_piece(to).is_none() {
+            IllegalMoveReason::NothingToCapture
+        } else if reaches(&captures, 1) {
+            IllegalMoveReason::PathBlocked
+        } else {
+            IllegalMoveReason::NotInPattern
+        }
+    }
+
+    /// Returns the positions the king of the given color at the given position can castle to.
+    /// The king and rook must still have their castling right, the squares between them must be empty,
+    /// and the king may not castle out of check, through an attacked square or into check.
+    fn get_castling_moves(&self, position: &BoardPosition, color: Color) -> Vec<BoardPosition> {
+        let mut moves = vec![];
+        if *position != BoardPosition::new(File::E, color.back_rank()) || self.is_in_check(color) {
+            return moves;
+        }
+
+        for side in [CastlingSide::Kingside, CastlingSide::Queenside] {
+            let rook_position = BoardPosition::new(side.rook_file(), position.rank);
+            if !self.castling_rights.has(color, side)
+                || self.get_piece(&rook_position) != Some(Rook(color))
+            {
+                continue;
+            }
+            if side.files_between().iter().any(|file| {
+                self.get_piece(&BoardPosition::new(*file, position.rank))
+                    .is_some()
+            }) {
+                continue;
+            }
+
+            //The squares the king passes over and lands on may not be attacked
+            let passed = BoardPosition::new(side.rook_destination_file(), position.rank);
+            let destination = BoardPosition::new(side.king_destination_file(), position.rank);
+            let attacked = [passed, destination]
+                .iter()
+                .any(|square| self.board.is_square_attacked(&square.into(), color.other()));
+
+            if !attacked {
+                moves.push(destination);
+            }
+        }
+        moves
+    }
+
+    /// Returns the positions a pawn of the given color can move to from the given position.
+    /// The pawn only moves forward onto empty squares, two steps if it is on its starting rank and both squares are empty.
+    /// It only captures diagonally forward, onto enemy pieces or en passant onto the en passant target square.
+    fn get_pawn_moves(&self, position: &BoardPosition, color: Color) -> Vec<BoardPosition> {
+        let steps = if position.rank == color.pawn_start_rank() {
+            2
+        } else {
+            1
+        };
+        let mut moves = vec![];
+
+        for pos in moveset::get_steps(&position.into(), &moveset::Move::Forward(color), steps) {
+            if self.board.get_piece(&pos).is_some() {
+                break;
+            }
+            moves.push(pos.try_into().unwrap());
+        }
+
+        for capture in [
+            moveset::Move::ForwardLeft(color),
+            moveset::Move::ForwardRight(color),
+        ] {
+            if let Some(pos) = capture.get_position(&position.into(), 1) {
+                if let Some(piece) = self.board.get_piece(&pos) {
+                    if piece.get_color() != color && !matches!(piece, King(_)) {
+                        moves.push(pos.try_into().unwrap());
+                    }
+                } else if color == self.get_turn() && self.en_passant == pos.try_into().ok() {
+                    moves.push(pos.try_into().unwrap());
+                }
+            }
         }
-        None
+        moves
     }
 
     /// Returns the piece as an option at the given position.
@@ -445,7 +1451,7 @@ impl Game {
         }
         if let Some(moves) = self.get_possible_moves(position) {
             for (y, rank) in self.board.piece_array.iter().enumerate() {
-                print!("\n");
+                println!();
                 for (x, _file) in rank.iter().enumerate() {
                     let pos: BoardPosition =
                         Position::new(x, (7usize).abs_diff(y)).try_into().unwrap();
@@ -456,7 +1462,7 @@ impl Game {
                     }
                 }
             }
-            print!("\n")
+            println!()
         }
         println!("\n--------------------------\n");
     }
@@ -464,9 +1470,9 @@ impl Game {
 
 impl fmt::Debug for Game {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        for (_y, rank) in self.board.piece_array.iter().enumerate() {
-            write!(f, "\n")?;
-            for (_x, p) in rank.iter().enumerate() {
+        for rank in self.board.piece_array.iter() {
+            writeln!(f)?;
+            for p in rank.iter() {
                 if let Some(piece) = p {
                     write!(f, " {}", piece.char())?;
                 } else {
@@ -474,6 +1480,6 @@ impl fmt::Debug for Game {
                 }
             }
         }
-        write!(f, "\n")
+        writeln!(f)
     }
 }
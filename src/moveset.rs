@@ -1,5 +1,5 @@
 use crate::*;
-use Move::*;
+use self::Move::*;
 
 ///Moveset
 pub struct Moveset {
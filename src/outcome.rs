@@ -0,0 +1,72 @@
+use crate::*;
+
+///GameResult
+/// Who, if anyone, won a finished game.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum GameResult {
+    ///The color won the game
+    Win(Color),
+    ///Neither player won the game
+    Draw,
+}
+
+///GameOverReason
+/// Why a finished game ended.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum GameOverReason {
+    Checkmate,
+    Resignation,
+    Agreement,
+    Stalemate,
+    Repetition,
+    FiftyMoveRule,
+    InsufficientMaterial,
+    Timeout,
+}
+
+///GameOutcome
+/// The result of a finished game together with the reason it ended, so that it can be shown to the players.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct GameOutcome {
+    pub result: GameResult,
+    pub reason: GameOverReason,
+}
+
+impl GameOutcome {
+    pub fn new(result: GameResult, reason: GameOverReason) -> Self {
+        GameOutcome { result, reason }
+    }
+
+    /// Returns the outcome of a game in the given state.
+    /// Returns None if the game is not over yet.
+    pub fn from_state(state: GameState) -> Option<GameOutcome> {
+        let outcome = match state {
+            GameState::InProgress | GameState::Check | GameState::Promotion(_) => return None,
+            GameState::Checkmate(winner) => {
+                GameOutcome::new(GameResult::Win(winner), GameOverReason::Checkmate)
+            }
+            GameState::Resigned(loser) => {
+                GameOutcome::new(GameResult::Win(loser.other()), GameOverReason::Resignation)
+            }
+            GameState::TimedOut(loser) => {
+                GameOutcome::new(GameResult::Win(loser.other()), GameOverReason::Timeout)
+            }
+            GameState::Stalemate => GameOutcome::new(GameResult::Draw, GameOverReason::Stalemate),
+            GameState::Draw(reason) => GameOutcome::new(
+                GameResult::Draw,
+                match reason {
+                    DrawReason::ThreefoldRepetition | DrawReason::FivefoldRepetition => {
+                        GameOverReason::Repetition
+                    }
+                    DrawReason::FiftyMoveRule | DrawReason::SeventyFiveMoveRule => {
+                        GameOverReason::FiftyMoveRule
+                    }
+                    DrawReason::InsufficientMaterial => GameOverReason::InsufficientMaterial,
+                    DrawReason::Agreement => GameOverReason::Agreement,
+                    DrawReason::Timeout => GameOverReason::Timeout,
+                },
+            ),
+        };
+        Some(outcome)
+    }
+}
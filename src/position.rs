@@ -1,5 +1,5 @@
 use core::fmt;
-use std::{ops::Mul, usize};
+use std::ops::Mul;
 
 use File::*;
 use Rank::*;
@@ -278,6 +278,15 @@ impl fmt::Debug for BoardPosition {
     }
 }
 
+/// Writes the position the way it is written in chess notation, with a lowercase file like `e4`
+impl fmt::Display for BoardPosition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let file: char = self.file.into();
+        let rank: char = self.rank.into();
+        write!(f, "{}{}", file.to_ascii_lowercase(), rank)
+    }
+}
+
 impl fmt::Debug for Position {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "x:{} y:{}", self.x, self.y)
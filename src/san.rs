@@ -0,0 +1,232 @@
+use crate::*;
+
+///SanError
+/// Why a move in Standard Algebraic Notation could not be read or made.
+#[derive(Debug, PartialEq, Eq)]
+pub enum SanError {
+    ///The notation is empty or too short to be a move
+    InvalidNotation,
+    ///A character that does not belong in the notation
+    InvalidCharacter(char),
+    ///None of the legal moves matches the notation
+    NoMatchingMove,
+    ///More than one legal move matches the notation, it needs the file or rank the piece moves from
+    AmbiguousMove,
+    ///The move was read, but the game did not allow it to be made
+    MoveFailed(ChessError),
+}
+
+impl Game {
+    /// Returns the move written in Standard Algebraic Notation, like `Nbd7`, `exd6`, `e8=Q+` or `O-O-O#`.
+    /// The move is expected to be one of the legal moves in the current position, or the null move.
+    pub fn san(&mut self, chess_move: &Move) -> String {
+        //The null move has no standard notation, `--` is what most programs write
+        if chess_move.is_null() {
+            return "--".to_string();
+        }
+        let piece = self
+            .get_piece(&chess_move.from)
+            .expect("There is no piece to move");
+        let mut san = String::new();
+
+        match chess_move.kind {
+            MoveKind::Castle(CastlingSide::Kingside) => san.push_str("O-O"),
+            MoveKind::Castle(CastlingSide::Queenside) => san.push_str("O-O-O"),
+            _ => {
+                if let Pawn(_) = piece {
+                    //A pawn capture is written with the file the pawn came from
+                    if chess_move.is_capture() {
+                        san.push(char::from(chess_move.from.file).to_ascii_lowercase());
+                    }
+                } else {
+                    san.push(piece_letter(piece.kind()));
+                    san.push_str(&self.get_disambiguation(piece, chess_move));
+                }
+                if chess_move.is_capture() {
+                    san.push('x');
+                }
+                san.push_str(&chess_move.to.to_string());
+                if let Some(promotion) = chess_move.promotion {
+                    san.push('=');
+                    san.push(piece_letter(promotion));
+                }
+            }
+        }
+
+        //Make the move to see if it gives check or checkmate
+        let undo = self.make_move(*chess_move);
+        if self.is_check() {
+            san.push(if self.has_legal_moves() { '+' } else { '#' });
+        }
+        self.unmake_move(undo);
+        san
+    }
+
+    /// Returns the legal move written in Standard Algebraic Notation.
+    /// Common variants are accepted as well, such as `0-0` for castling, `e8Q` for promotions, `ef6` for pawn captures,
+    /// `e.p.` after en passant captures and `+`, `#`, `!` or `?` after the move.
+    /// Returns the move or a SAN error explaining why no single legal move matches.
+    pub fn parse_san(&mut self, san: &str) -> Result<Move, SanError> {
+        //Remove everything written after the move itself
+        let mut notation = san.trim();
+        loop {
+            let stripped = notation
+                .trim_end_matches(['+', '#', '!', '?'])
+                .trim_end_matches("e.p.")
+                .trim_end();
+            if stripped == notation {
+                break;
+            }
+            notation = stripped;
+        }
+        if notation.is_empty() {
+            return Err(SanError::InvalidNotation);
+        }
+
+        let legal_moves = self.legal_moves();
+        let castling_side = match notation {
+            "O-O" | "0-0" => Some(CastlingSide::Kingside),
+            "O-O-O" | "0-0-0" => Some(CastlingSide::Queenside),
+            _ => None,
+        };
+        if let Some(side) = castling_side {
+            return legal_moves
+                .into_iter()
+                .find(|m| m.kind == MoveKind::Castle(side))
+                .ok_or(SanError::NoMatchingMove);
+        }
+
+        let mut is_capture = notation.contains('x');
+        let mut chars: Vec<char> = notation.chars().filter(|c| *c != 'x').collect();
+
+        //The piece letter comes first, pawns have none
+        let kind = match chars.first().copied().and_then(letter_piece) {
+            Some(kind) => {
+                chars.remove(0);
+                kind
+            }
+            None => PieceKind::Pawn,
+        };
+
+        //The promotion comes last, with or without an equals sign
+        let mut promotion = None;
+        if chars.len() > 2 {
+            if let Some(promoted) = letter_piece(chars[chars.len() - 1].to_ascii_uppercase()) {
+                promotion = Some(promoted);
+                chars.pop();
+                if chars.last() == Some(&'=') {
+                    chars.pop();
+                }
+            }
+        }
+
+        //Then the destination square, and before it the file and rank the piece moves from if they are needed
+        if chars.len() < 2 || chars.len() > 4 {
+            return Err(SanError::InvalidNotation);
+        }
+        let rank = chars.pop().unwrap();
+        let rank = Rank::try_from(rank).map_err(|_| SanError::InvalidCharacter(rank))?;
+        let file = chars.pop().unwrap();
+        let file = match file {
+            'a'..='h' => File::try_from(file.to_ascii_uppercase()).unwrap(),
+            _ => return Err(SanError::InvalidCharacter(file)),
+        };
+        let to = BoardPosition::new(file, rank);
+
+        let mut from_file = None;
+        let mut from_rank = None;
+        for c in chars {
+            match c {
+                'a'..='h' => from_file = File::try_from(c.to_ascii_uppercase()).ok(),
+                '1'..='8' => from_rank = Rank::try_from(c).ok(),
+                _ => return Err(SanError::InvalidCharacter(c)),
+            }
+        }
+
+        //A pawn push stays on its file, a pawn capture names the file the pawn comes from.
+        //The x is sometimes left out, like `ef6`, but the file still makes it a capture
+        if kind == PieceKind::Pawn && from_file.is_some_and(|file| file != to.file) {
+            is_capture = true;
+        }
+        if kind == PieceKind::Pawn && is_capture && from_file.is_none() {
+            return Err(SanError::InvalidNotation);
+        }
+
+        let mut matching: Vec<Move> = legal_moves
+            .into_iter()
+            .filter(|m| m.to == to && m.promotion == promotion)
+            .filter(|m| match (kind, is_capture) {
+                (PieceKind::Pawn, true) => m.is_capture(),
+                (PieceKind::Pawn, false) => !m.is_capture() && m.from.file == to.file,
+                _ => true,
+            })
+            .filter(|m| from_file.is_none_or(|file| m.from.file == file))
+            .filter(|m| from_rank.is_none_or(|rank| m.from.rank == rank))
+            .filter(|m| {
+                self.get_piece(&m.from)
+                    .is_some_and(|piece| piece.kind() == kind)
+            })
+            .collect();
+
+        match matching.len() {
+            0 => Err(SanError::NoMatchingMove),
+            1 => Ok(matching.remove(0)),
+            _ => Err(SanError::AmbiguousMove),
+        }
+    }
+
+    /// Makes the move written in Standard Algebraic Notation, see `parse_san`.
+    /// Returns the new game state or a SAN error explaining why the move could not be made.
+    pub fn play_san(&mut self, san: &str) -> Result<GameState, SanError> {
+        if self.is_game_over() {
+            return Err(SanError::MoveFailed(ChessError::GameAlreadyOver));
+        }
+        if let GameState::Promotion(_) = self.get_game_state() {
+            return Err(SanError::MoveFailed(ChessError::PromoteFirst));
+        }
+        let chess_move = self.parse_san(san)?;
+        self.move_piece_with_promotion(&chess_move.from, &chess_move.to, chess_move.promotion)
+            .map_err(SanError::MoveFailed)
+    }
+
+    /// Returns what has to be written after the piece letter to tell the move apart
+    /// from the moves of other pieces of the same kind to the same square.
+    /// That is the file if it is enough, otherwise the rank, otherwise the whole square.
+    fn get_disambiguation(&mut self, piece: Piece, chess_move: &Move) -> String {
+        let others: Vec<BoardPosition> = self
+            .legal_moves_for(piece.get_color())
+            .into_iter()
+            .filter(|m| m.to == chess_move.to && m.from != chess_move.from)
+            .filter(|m| self.get_piece(&m.from) == Some(piece))
+            .map(|m| m.from)
+            .collect();
+
+        let from = chess_move.from;
+        if others.is_empty() {
+            String::new()
+        } else if others.iter().all(|other| other.file != from.file) {
+            char::from(from.file).to_ascii_lowercase().to_string()
+        } else if others.iter().all(|other| other.rank != from.rank) {
+            char::from(from.rank).to_string()
+        } else {
+            from.to_string()
+        }
+    }
+}
+
+/// Returns the uppercase letter used for the kind of piece in algebraic notation
+fn piece_letter(kind: PieceKind) -> char {
+    kind.with_color(Color::White).char()
+}
+
+/// Returns the kind of piece written with the uppercase letter, pawns have no letter
+fn letter_piece(letter: char) -> Option<PieceKind> {
+    match letter {
+        'K' => Some(PieceKind::King),
+        'Q' => Some(PieceKind::Queen),
+        'R' => Some(PieceKind::Rook),
+        'B' => Some(PieceKind::Bishop),
+        'N' => Some(PieceKind::Knight),
+        _ => None,
+    }
+}
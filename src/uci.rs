@@ -0,0 +1,76 @@
+use crate::*;
+
+///UciError
+/// Why a move in UCI long algebraic notation could not be read or made.
+#[derive(Debug, PartialEq, Eq)]
+pub enum UciError {
+    ///The notation is not four or five characters long
+    InvalidNotation,
+    ///A character that does not belong in the notation
+    InvalidCharacter(char),
+    ///None of the legal moves goes between the squares with the promotion
+    NoMatchingMove,
+    ///The null move `0000` can only be made with `make_move`, not played in the game
+    NullMove,
+    ///The move was read, but the game did not allow it to be made
+    MoveFailed(ChessError),
+}
+
+impl Game {
+    /// Returns the legal move written in UCI long algebraic notation, like `e2e4`, `e7e8q` or `e1g1` for castling.
+    /// Upper and lowercase letters are both accepted, and `0000` is read as the null move.
+    /// Returns the move or a UCI error explaining why no legal move matches.
+    pub fn parse_uci(&mut self, uci: &str) -> Result<Move, UciError> {
+        let notation = uci.trim().to_ascii_lowercase();
+        if notation == "0000" {
+            return Ok(Move::null());
+        }
+        let chars: Vec<char> = notation.chars().collect();
+        if chars.len() != 4 && chars.len() != 5 {
+            return Err(UciError::InvalidNotation);
+        }
+
+        let from = parse_square(chars[0], chars[1])?;
+        let to = parse_square(chars[2], chars[3])?;
+        let promotion = match chars.get(4) {
+            None => None,
+            Some('n') => Some(PieceKind::Knight),
+            Some('b') => Some(PieceKind::Bishop),
+            Some('r') => Some(PieceKind::Rook),
+            Some('q') => Some(PieceKind::Queen),
+            Some(character) => return Err(UciError::InvalidCharacter(*character)),
+        };
+
+        self.legal_moves()
+            .into_iter()
+            .find(|m| m.from == from && m.to == to && m.promotion == promotion)
+            .ok_or(UciError::NoMatchingMove)
+    }
+
+    /// Plays the move written in UCI long algebraic notation, see `parse_uci` for what is accepted.
+    /// Returns the new game state or a UCI error if the move could not be read or made.
+    pub fn play_uci(&mut self, uci: &str) -> Result<GameState, UciError> {
+        if self.is_game_over() {
+            return Err(UciError::MoveFailed(ChessError::GameAlreadyOver));
+        }
+        if let GameState::Promotion(_) = self.get_game_state() {
+            return Err(UciError::MoveFailed(ChessError::PromoteFirst));
+        }
+        let chess_move = self.parse_uci(uci)?;
+        if chess_move.is_null() {
+            return Err(UciError::NullMove);
+        }
+        self.move_piece_with_promotion(&chess_move.from, &chess_move.to, chess_move.promotion)
+            .map_err(UciError::MoveFailed)
+    }
+}
+
+/// Reads a lowercase square like `e4` from its file and rank
+fn parse_square(file: char, rank: char) -> Result<BoardPosition, UciError> {
+    let file = match file {
+        'a'..='h' => File::try_from(file.to_ascii_uppercase()).unwrap(),
+        _ => return Err(UciError::InvalidCharacter(file)),
+    };
+    let rank = Rank::try_from(rank).map_err(|_| UciError::InvalidCharacter(rank))?;
+    Ok(BoardPosition::new(file, rank))
+}
@@ -0,0 +1,116 @@
+use olle_chess::*;
+use position::*;
+
+fn setup_empty_with_kings() -> Game {
+    let mut game = Game::empty();
+    game.board.spawn_piece(Piece::King(Color::White), &BoardPosition::new(File::E, Rank::One).into()).unwrap();
+    game.board.spawn_piece(Piece::King(Color::Black), &BoardPosition::new(File::E, Rank::Eight).into()).unwrap();
+    game
+}
+
+#[test]
+fn test_no_checkers_when_not_in_check() {
+    let game = Game::new();
+    assert!(game.checkers().is_empty());
+}
+
+#[test]
+fn test_double_check_has_two_checkers() {
+    let mut game = setup_empty_with_kings();
+    game.board.spawn_piece(Piece::Rook(Color::Black), &BoardPosition::new(File::E, Rank::Five).into()).unwrap();
+    game.board.spawn_piece(Piece::Knight(Color::Black), &BoardPosition::new(File::D, Rank::Three).into()).unwrap();
+
+    let mut checkers = game.checkers();
+    checkers.sort();
+    assert_eq!(checkers, vec![BoardPosition::new(File::D, Rank::Three), BoardPosition::new(File::E, Rank::Five)]);
+}
+
+#[test]
+fn test_pinned_pieces() {
+    let mut game = setup_empty_with_kings();
+    game.board.spawn_piece(Piece::Knight(Color::White), &BoardPosition::new(File::E, Rank::Three).into()).unwrap();
+    game.board.spawn_piece(Piece::Rook(Color::Black), &BoardPosition::new(File::E, Rank::Five).into()).unwrap();
+    game.board.spawn_piece(Piece::Pawn(Color::White), &BoardPosition::new(File::D, Rank::Two).into()).unwrap();
+    game.board.spawn_piece(Piece::Queen(Color::Black), &BoardPosition::new(File::A, Rank::Five).into()).unwrap();
+
+    let pins = game.pinned_pieces(Color::White);
+    assert_eq!(pins.len(), 2);
+
+    let rook_pin = pins.iter().find(|pin| pin.pinner == BoardPosition::new(File::E, Rank::Five)).unwrap();
+    assert_eq!(rook_pin.pinned, BoardPosition::new(File::E, Rank::Three));
+    assert_eq!(rook_pin.ray, vec![
+        BoardPosition::new(File::E, Rank::Two),
+        BoardPosition::new(File::E, Rank::Three),
+        BoardPosition::new(File::E, Rank::Four),
+        BoardPosition::new(File::E, Rank::Five),
+    ]);
+
+    let queen_pin = pins.iter().find(|pin| pin.pinner == BoardPosition::new(File::A, Rank::Five)).unwrap();
+    assert_eq!(queen_pin.pinned, BoardPosition::new(File::D, Rank::Two));
+}
+
+#[test]
+fn test_two_pieces_in_the_way_are_not_pinned() {
+    let mut game = setup_empty_with_kings();
+    game.board.spawn_piece(Piece::Knight(Color::White), &BoardPosition::new(File::E, Rank::Two).into()).unwrap();
+    game.board.spawn_piece(Piece::Knight(Color::White), &BoardPosition::new(File::E, Rank::Three).into()).unwrap();
+    game.board.spawn_piece(Piece::Rook(Color::Black), &BoardPosition::new(File::E, Rank::Five).into()).unwrap();
+    // A bishop does not pin along a file
+    game.board.spawn_piece(Piece::Pawn(Color::White), &BoardPosition::new(File::D, Rank::One).into()).unwrap();
+    game.board.spawn_piece(Piece::Bishop(Color::Black), &BoardPosition::new(File::A, Rank::One).into()).unwrap();
+
+    assert!(game.pinned_pieces(Color::White).is_empty());
+}
+
+#[test]
+fn test_opponent_moves_do_not_depend_on_turn() {
+    let mut game = setup_empty_with_kings();
+    game.board.spawn_piece(Piece::Rook(Color::Black), &BoardPosition::new(File::A, Rank::Eight).into()).unwrap();
+    game.board.spawn_piece(Piece::Pawn(Color::White), &BoardPosition::new(File::A, Rank::Two).into()).unwrap();
+    game.board.spawn_piece(Piece::Pawn(Color::Black), &BoardPosition::new(File::A, Rank::Five).into()).unwrap();
+
+    //It is whites turn, but the black rook can still capture the white pawn and not its own
+    let moves = game.get_possible_moves(&BoardPosition::new(File::A, Rank::Eight)).unwrap();
+    assert!(moves.contains(&BoardPosition::new(File::A, Rank::Six)));
+    assert!(!moves.contains(&BoardPosition::new(File::A, Rank::Five)));
+    assert!(moves.contains(&BoardPosition::new(File::B, Rank::Eight)));
+    assert!(game.is_legal_move(&BoardPosition::new(File::A, Rank::Eight), &BoardPosition::new(File::D, Rank::Eight)));
+    assert!(!game.is_legal_move(&BoardPosition::new(File::A, Rank::Eight), &BoardPosition::new(File::A, Rank::Four)));
+}
+
+#[test]
+fn test_legal_moves_for_the_other_side() {
+    let mut game = Game::new();
+    let black_moves = game.legal_moves_for(Color::Black);
+
+    assert_eq!(black_moves.len(), 20);
+    assert!(black_moves.iter().all(|m| game.get_piece(&m.from).unwrap().get_color() == Color::Black));
+    assert_eq!(game.get_turn(), Color::White);
+}
+
+#[test]
+fn test_other_side_cannot_leave_its_king_in_check() {
+    let mut game = setup_empty_with_kings();
+    game.board.spawn_piece(Piece::Rook(Color::White), &BoardPosition::new(File::A, Rank::Seven).into()).unwrap();
+
+    let moves = game.get_possible_moves(&BoardPosition::new(File::E, Rank::Eight)).unwrap();
+    assert!(!moves.contains(&BoardPosition::new(File::E, Rank::Seven)));
+    assert!(moves.contains(&BoardPosition::new(File::F, Rank::Eight)));
+}
+
+#[test]
+fn test_attacked_squares_and_check_for_either_color() {
+    let mut game = setup_empty_with_kings();
+    game.board.spawn_piece(Piece::Rook(Color::Black), &BoardPosition::new(File::H, Rank::One).into()).unwrap();
+
+    assert!(game.is_in_check(Color::White));
+    assert!(!game.is_in_check(Color::Black));
+
+    let attacked = game.attacked_squares(Color::Black);
+    assert!(attacked.contains(&BoardPosition::new(File::F, Rank::One)));
+    assert!(attacked.contains(&BoardPosition::new(File::E, Rank::One)));
+    assert!(attacked.contains(&BoardPosition::new(File::H, Rank::Eight)));
+    assert!(attacked.contains(&BoardPosition::new(File::D, Rank::Seven)));
+    assert!(!attacked.contains(&BoardPosition::new(File::D, Rank::One)));
+    assert!(!attacked.contains(&BoardPosition::new(File::E, Rank::Eight)));
+}
@@ -1,5 +1,5 @@
 use olle_chess::*;
-use olle_chess::board::Board;
+use olle_chess::board::{Board, PositionIssue};
 use olle_chess::position::*;
 
 //ChatGPT skrev dessa tester också lol
@@ -110,3 +110,148 @@ fn test_board_display() {
     assert!(board_display.contains("H8"));
 }
 
+
+#[test]
+fn test_insufficient_material() {
+    let mut board = Board::new();
+    board.set_piece(Piece::King(Color::White), &Position::new(4, 0));
+    board.set_piece(Piece::King(Color::Black), &Position::new(4, 7));
+    assert!(board.is_insufficient_material());
+
+    // King and knight against king
+    board.set_piece(Piece::Knight(Color::White), &Position::new(1, 0));
+    assert!(board.is_insufficient_material());
+
+    // Two knights are enough to mate with help from the opponent
+    board.set_piece(Piece::Knight(Color::White), &Position::new(6, 0));
+    assert!(!board.is_insufficient_material());
+}
+
+#[test]
+fn test_insufficient_material_with_bishops() {
+    let mut board = Board::new();
+    board.set_piece(Piece::King(Color::White), &Position::new(4, 0));
+    board.set_piece(Piece::King(Color::Black), &Position::new(4, 7));
+    // c1 and f8 are both dark squares
+    board.set_piece(Piece::Bishop(Color::White), &Position::new(2, 0));
+    assert!(board.is_insufficient_material());
+    board.set_piece(Piece::Bishop(Color::Black), &Position::new(5, 7));
+    assert!(board.is_insufficient_material());
+
+    // c8 is a light square
+    board.despawn_piece(&Position::new(5, 7));
+    board.set_piece(Piece::Bishop(Color::Black), &Position::new(2, 7));
+    assert!(!board.is_insufficient_material());
+}
+
+#[test]
+fn test_pawns_are_sufficient_material() {
+    let mut board = Board::new();
+    board.set_piece(Piece::King(Color::White), &Position::new(4, 0));
+    board.set_piece(Piece::King(Color::Black), &Position::new(4, 7));
+    board.set_piece(Piece::Pawn(Color::Black), &Position::new(0, 6));
+    assert!(!board.is_insufficient_material());
+}
+
+#[test]
+fn test_pawns_attack_diagonally_forward() {
+    let mut board = Board::new();
+    board.set_piece(Piece::Pawn(Color::White), &Position::new(3, 3));
+    board.set_piece(Piece::Pawn(Color::Black), &Position::new(3, 6));
+
+    // The white pawn on d4 attacks c5 and e5, but not d5 or anything behind it
+    assert!(board.is_square_attacked(&Position::new(2, 4), Color::White));
+    assert!(board.is_square_attacked(&Position::new(4, 4), Color::White));
+    assert!(!board.is_square_attacked(&Position::new(3, 4), Color::White));
+    assert!(!board.is_square_attacked(&Position::new(2, 2), Color::White));
+
+    // The black pawn on d7 attacks c6 and e6
+    assert!(board.is_square_attacked(&Position::new(2, 5), Color::Black));
+    assert!(board.is_square_attacked(&Position::new(4, 5), Color::Black));
+    assert!(!board.is_square_attacked(&Position::new(3, 5), Color::Black));
+}
+
+#[test]
+fn test_sliding_attacks_are_blocked() {
+    let mut board = Board::new();
+    board.set_piece(Piece::Rook(Color::Black), &Position::new(0, 0));
+    board.set_piece(Piece::Knight(Color::White), &Position::new(3, 0));
+
+    assert!(board.is_square_attacked(&Position::new(2, 0), Color::Black));
+    assert!(board.is_square_attacked(&Position::new(3, 0), Color::Black));
+    assert!(!board.is_square_attacked(&Position::new(4, 0), Color::Black));
+    assert!(!board.is_square_attacked(&Position::new(1, 1), Color::Black));
+}
+
+#[test]
+fn test_attackers_of() {
+    let mut board = Board::new();
+    board.set_piece(Piece::Queen(Color::Black), &Position::new(7, 7));
+    board.set_piece(Piece::Knight(Color::Black), &Position::new(2, 2));
+    board.set_piece(Piece::King(Color::Black), &Position::new(5, 4));
+    board.set_piece(Piece::Bishop(Color::White), &Position::new(0, 0));
+
+    let mut attackers = board.attackers_of(&Position::new(4, 4), Color::Black);
+    attackers.sort();
+    assert_eq!(attackers, vec![Position::new(5, 4), Position::new(7, 7)]);
+
+    let attackers = board.attackers_of(&Position::new(4, 4), Color::White);
+    assert!(attackers.is_empty());
+}
+
+#[test]
+fn test_starting_position_is_valid() {
+    let game = Game::new();
+    assert!(game.board.validate(Color::White).is_empty());
+}
+
+#[test]
+fn test_validate_kings() {
+    let mut board = Board::new();
+    board.set_piece(Piece::King(Color::White), &Position::new(4, 0));
+    board.set_piece(Piece::King(Color::White), &Position::new(0, 0));
+
+    let issues = board.validate(Color::White);
+    assert!(issues.contains(&PositionIssue::TooManyKings(Color::White)));
+    assert!(issues.contains(&PositionIssue::MissingKing(Color::Black)));
+    assert_eq!(issues.len(), 2);
+}
+
+#[test]
+fn test_validate_pawns_on_back_rank() {
+    let mut board = Board::new();
+    board.set_piece(Piece::King(Color::White), &Position::new(4, 0));
+    board.set_piece(Piece::King(Color::Black), &Position::new(4, 7));
+    board.set_piece(Piece::Pawn(Color::White), &Position::new(0, 0));
+    board.set_piece(Piece::Pawn(Color::Black), &Position::new(7, 7));
+
+    let issues = board.validate(Color::White);
+    assert_eq!(issues, vec![
+        PositionIssue::PawnOnBackRank(BoardPosition::new(File::A, Rank::One)),
+        PositionIssue::PawnOnBackRank(BoardPosition::new(File::H, Rank::Eight)),
+    ]);
+}
+
+#[test]
+fn test_validate_piece_counts() {
+    let mut game = Game::new();
+    //A second queen next to eight pawns cannot come from a promotion, and makes 17 pieces
+    game.board.set_piece(Piece::Queen(Color::Black), &Position::new(0, 5));
+    let issues = game.board.validate(Color::White);
+    assert_eq!(issues, vec![PositionIssue::TooManyPieces(Color::Black), PositionIssue::ImpossiblePromotions(Color::Black)]);
+
+    //Two queens are fine when a pawn is missing
+    game.board.despawn_piece(&Position::new(0, 6));
+    assert!(game.board.validate(Color::White).is_empty());
+}
+
+#[test]
+fn test_validate_side_not_to_move_in_check() {
+    let mut board = Board::new();
+    board.set_piece(Piece::King(Color::White), &Position::new(4, 0));
+    board.set_piece(Piece::King(Color::Black), &Position::new(4, 7));
+    board.set_piece(Piece::Rook(Color::White), &Position::new(4, 4));
+
+    assert_eq!(board.validate(Color::White), vec![PositionIssue::OpponentInCheck(Color::Black)]);
+    assert!(board.validate(Color::Black).is_empty());
+}
@@ -0,0 +1,120 @@
+use olle_chess::castling::*;
+use olle_chess::*;
+use position::*;
+
+// Support function that clears the back rank between the kings and rooks of both players
+fn setup_castling_game() -> Game {
+    let mut game = Game::new();
+    for file in [File::B, File::C, File::D, File::F, File::G] {
+        game.board.despawn_piece(&BoardPosition::new(file, Rank::One).into());
+        game.board.despawn_piece(&BoardPosition::new(file, Rank::Eight).into());
+    }
+    game
+}
+
+#[test]
+fn test_white_can_castle_kingside() {
+    let mut game = setup_castling_game();
+
+    let moves = game.get_possible_moves(&BoardPosition::new(File::E, Rank::One)).unwrap();
+    assert!(moves.contains(&BoardPosition::new(File::G, Rank::One)));
+    assert!(moves.contains(&BoardPosition::new(File::C, Rank::One)));
+
+    game.move_piece(&BoardPosition::new(File::E, Rank::One), &BoardPosition::new(File::G, Rank::One)).unwrap();
+
+    assert_eq!(game.get_piece(&BoardPosition::new(File::G, Rank::One)), Some(Piece::King(Color::White)));
+    assert_eq!(game.get_piece(&BoardPosition::new(File::F, Rank::One)), Some(Piece::Rook(Color::White)));
+    assert_eq!(game.get_piece(&BoardPosition::new(File::H, Rank::One)), None);
+    assert!(!game.get_castling_rights().has(Color::White, CastlingSide::Kingside));
+    assert!(!game.get_castling_rights().has(Color::White, CastlingSide::Queenside));
+}
+
+#[test]
+fn test_black_can_castle_queenside() {
+    let mut game = setup_castling_game();
+    game.move_piece(&BoardPosition::new(File::A, Rank::Two), &BoardPosition::new(File::A, Rank::Three)).unwrap();
+
+    game.move_piece(&BoardPosition::new(File::E, Rank::Eight), &BoardPosition::new(File::C, Rank::Eight)).unwrap();
+
+    assert_eq!(game.get_piece(&BoardPosition::new(File::C, Rank::Eight)), Some(Piece::King(Color::Black)));
+    assert_eq!(game.get_piece(&BoardPosition::new(File::D, Rank::Eight)), Some(Piece::Rook(Color::Black)));
+    assert_eq!(game.get_piece(&BoardPosition::new(File::A, Rank::Eight)), None);
+}
+
+#[test]
+fn test_castling_rights_are_lost_when_the_rook_moves() {
+    let mut game = setup_castling_game();
+    game.move_piece(&BoardPosition::new(File::H, Rank::One), &BoardPosition::new(File::G, Rank::One)).unwrap();
+    game.move_piece(&BoardPosition::new(File::A, Rank::Seven), &BoardPosition::new(File::A, Rank::Six)).unwrap();
+    game.move_piece(&BoardPosition::new(File::G, Rank::One), &BoardPosition::new(File::H, Rank::One)).unwrap();
+    game.move_piece(&BoardPosition::new(File::A, Rank::Six), &BoardPosition::new(File::A, Rank::Five)).unwrap();
+
+    assert!(!game.get_castling_rights().has(Color::White, CastlingSide::Kingside));
+    assert!(game.get_castling_rights().has(Color::White, CastlingSide::Queenside));
+    let result = game.move_piece(&BoardPosition::new(File::E, Rank::One), &BoardPosition::new(File::G, Rank::One));
+    assert_eq!(result, Err(ChessError::IllegalMove(IllegalMoveReason::NoCastlingRights)));
+}
+
+#[test]
+fn test_castling_rights_are_lost_when_the_king_moves() {
+    let mut game = setup_castling_game();
+    game.move_piece(&BoardPosition::new(File::E, Rank::One), &BoardPosition::new(File::F, Rank::One)).unwrap();
+    game.move_piece(&BoardPosition::new(File::A, Rank::Seven), &BoardPosition::new(File::A, Rank::Six)).unwrap();
+    game.move_piece(&BoardPosition::new(File::F, Rank::One), &BoardPosition::new(File::E, Rank::One)).unwrap();
+    game.move_piece(&BoardPosition::new(File::A, Rank::Six), &BoardPosition::new(File::A, Rank::Five)).unwrap();
+
+    let moves = game.get_possible_moves(&BoardPosition::new(File::E, Rank::One)).unwrap();
+    assert!(!moves.contains(&BoardPosition::new(File::G, Rank::One)));
+    assert!(!moves.contains(&BoardPosition::new(File::C, Rank::One)));
+}
+
+#[test]
+fn test_castling_rights_are_lost_when_the_rook_is_captured() {
+    let mut game = setup_castling_game();
+    game.board.despawn_piece(&BoardPosition::new(File::G, Rank::Seven).into());
+    game.board.set_piece(Piece::Bishop(Color::White), &BoardPosition::new(File::B, Rank::Two).into());
+
+    // Bishop takes the rook on h8
+    game.move_piece(&BoardPosition::new(File::B, Rank::Two), &BoardPosition::new(File::H, Rank::Eight)).unwrap();
+
+    assert!(!game.get_castling_rights().has(Color::Black, CastlingSide::Kingside));
+    assert!(game.get_castling_rights().has(Color::Black, CastlingSide::Queenside));
+}
+
+#[test]
+fn test_cannot_castle_through_an_attacked_square() {
+    let mut game = setup_castling_game();
+    game.board.despawn_piece(&BoardPosition::new(File::F, Rank::Two).into());
+    game.board.spawn_piece(Piece::Rook(Color::Black), &BoardPosition::new(File::F, Rank::Four).into()).unwrap();
+
+    let moves = game.get_possible_moves(&BoardPosition::new(File::E, Rank::One)).unwrap();
+    assert!(!moves.contains(&BoardPosition::new(File::G, Rank::One)));
+    assert!(moves.contains(&BoardPosition::new(File::C, Rank::One)));
+
+    let result = game.move_piece(&BoardPosition::new(File::E, Rank::One), &BoardPosition::new(File::G, Rank::One));
+    assert_eq!(result, Err(ChessError::IllegalMove(IllegalMoveReason::CastlingThroughCheck)));
+}
+
+#[test]
+fn test_cannot_castle_into_check() {
+    let mut game = setup_castling_game();
+    game.board.despawn_piece(&BoardPosition::new(File::C, Rank::Two).into());
+    game.board.spawn_piece(Piece::Rook(Color::Black), &BoardPosition::new(File::C, Rank::Four).into()).unwrap();
+
+    let result = game.move_piece(&BoardPosition::new(File::E, Rank::One), &BoardPosition::new(File::C, Rank::One));
+    assert_eq!(result, Err(ChessError::IllegalMove(IllegalMoveReason::LeavesKingInCheck)));
+}
+
+#[test]
+fn test_cannot_castle_out_of_check() {
+    let mut game = setup_castling_game();
+    game.board.despawn_piece(&BoardPosition::new(File::E, Rank::Two).into());
+    game.board.spawn_piece(Piece::Rook(Color::Black), &BoardPosition::new(File::E, Rank::Four).into()).unwrap();
+
+    let moves = game.get_possible_moves(&BoardPosition::new(File::E, Rank::One)).unwrap();
+    assert!(!moves.contains(&BoardPosition::new(File::G, Rank::One)));
+    assert!(!moves.contains(&BoardPosition::new(File::C, Rank::One)));
+
+    let result = game.move_piece(&BoardPosition::new(File::E, Rank::One), &BoardPosition::new(File::C, Rank::One));
+    assert_eq!(result, Err(ChessError::IllegalMove(IllegalMoveReason::CastlingOutOfCheck)));
+}
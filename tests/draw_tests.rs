@@ -0,0 +1,175 @@
+use olle_chess::*;
+use position::*;
+
+// Support function that moves both knights out and back again, repeating the position
+fn shuffle_knights(game: &mut Game) {
+    let moves = [
+        (BoardPosition::new(File::G, Rank::One), BoardPosition::new(File::F, Rank::Three)),
+        (BoardPosition::new(File::G, Rank::Eight), BoardPosition::new(File::F, Rank::Six)),
+        (BoardPosition::new(File::F, Rank::Three), BoardPosition::new(File::G, Rank::One)),
+        (BoardPosition::new(File::F, Rank::Six), BoardPosition::new(File::G, Rank::Eight)),
+    ];
+    for (from, to) in moves {
+        game.move_piece(&from, &to).unwrap();
+    }
+}
+
+#[test]
+fn test_repetition_count() {
+    let mut game = Game::new();
+    assert_eq!(game.get_repetition_count(), 1);
+
+    shuffle_knights(&mut game);
+    assert_eq!(game.get_repetition_count(), 2);
+
+    shuffle_knights(&mut game);
+    assert_eq!(game.get_repetition_count(), 3);
+}
+
+#[test]
+fn test_threefold_repetition_can_be_claimed() {
+    let mut game = Game::new();
+    shuffle_knights(&mut game);
+    assert_eq!(game.claim_draw(), Err(ChessError::NoDrawToClaim));
+    assert_eq!(game.get_game_state(), GameState::InProgress);
+
+    shuffle_knights(&mut game);
+    assert_eq!(game.claim_draw(), Ok(GameState::Draw(DrawReason::ThreefoldRepetition)));
+
+    let result = game.move_piece(&BoardPosition::new(File::E, Rank::Two), &BoardPosition::new(File::E, Rank::Four));
+    assert_eq!(result, Err(ChessError::GameAlreadyOver));
+}
+
+#[test]
+fn test_fivefold_repetition_ends_the_game() {
+    let mut game = Game::new();
+    for _ in 0..3 {
+        shuffle_knights(&mut game);
+    }
+    assert_eq!(game.get_game_state(), GameState::InProgress);
+
+    shuffle_knights(&mut game);
+    assert_eq!(game.get_game_state(), GameState::Draw(DrawReason::FivefoldRepetition));
+}
+
+#[test]
+fn test_castling_rights_are_part_of_the_repeated_position() {
+    let mut game = Game::new();
+    // The rook leaves and returns, the placement repeats but white can no longer castle kingside
+    game.board.despawn_piece(&BoardPosition::new(File::G, Rank::One).into());
+    game.move_piece(&BoardPosition::new(File::H, Rank::One), &BoardPosition::new(File::G, Rank::One)).unwrap();
+    game.move_piece(&BoardPosition::new(File::G, Rank::Eight), &BoardPosition::new(File::F, Rank::Six)).unwrap();
+    game.move_piece(&BoardPosition::new(File::G, Rank::One), &BoardPosition::new(File::H, Rank::One)).unwrap();
+    game.move_piece(&BoardPosition::new(File::F, Rank::Six), &BoardPosition::new(File::G, Rank::Eight)).unwrap();
+
+    assert_eq!(game.get_repetition_count(), 1);
+}
+
+// Support function that makes `halfmoves` rook moves without moving a pawn, capturing or repeating a position three times.
+// The white rook tours 12 squares and the black rook 11 squares, so the combined position only repeats every 264 halfmoves.
+fn setup_rook_tour(halfmoves: usize) -> Game {
+    let mut game = Game::empty();
+    game.board.spawn_piece(Piece::King(Color::White), &BoardPosition::new(File::E, Rank::One).into()).unwrap();
+    game.board.spawn_piece(Piece::King(Color::Black), &BoardPosition::new(File::E, Rank::Eight).into()).unwrap();
+
+    let tour = |squares: &[(File, Rank)]| -> Vec<BoardPosition> {
+        squares.iter().map(|(file, rank)| BoardPosition::new(*file, *rank)).collect()
+    };
+    let white_tour = tour(&[
+        (File::A, Rank::Two), (File::B, Rank::Two), (File::C, Rank::Two), (File::D, Rank::Two),
+        (File::D, Rank::Three), (File::D, Rank::Four), (File::C, Rank::Four), (File::C, Rank::Three),
+        (File::B, Rank::Three), (File::B, Rank::Four), (File::A, Rank::Four), (File::A, Rank::Three),
+    ]);
+    let black_tour = tour(&[
+        (File::A, Rank::Seven), (File::B, Rank::Seven), (File::C, Rank::Seven), (File::D, Rank::Seven),
+        (File::D, Rank::Six), (File::D, Rank::Five), (File::C, Rank::Five), (File::C, Rank::Six),
+        (File::B, Rank::Six), (File::B, Rank::Five), (File::A, Rank::Five),
+    ]);
+    game.board.spawn_piece(Piece::Rook(Color::White), &white_tour[0].into()).unwrap();
+    game.board.spawn_piece(Piece::Rook(Color::Black), &black_tour[0].into()).unwrap();
+
+    for halfmove in 0..halfmoves {
+        let (tour, step) = match halfmove % 2 {
+            0 => (&white_tour, halfmove / 2),
+            _ => (&black_tour, halfmove / 2),
+        };
+        let from = tour[step % tour.len()];
+        let to = tour[(step + 1) % tour.len()];
+        game.move_piece(&from, &to).unwrap();
+    }
+    game
+}
+
+#[test]
+fn test_halfmove_clock_and_fullmove_number() {
+    let mut game = Game::new();
+    assert_eq!(game.get_halfmove_clock(), 0);
+    assert_eq!(game.get_fullmove_number(), 1);
+
+    game.move_piece(&BoardPosition::new(File::G, Rank::One), &BoardPosition::new(File::F, Rank::Three)).unwrap();
+    assert_eq!(game.get_halfmove_clock(), 1);
+    assert_eq!(game.get_fullmove_number(), 1);
+
+    game.move_piece(&BoardPosition::new(File::B, Rank::Eight), &BoardPosition::new(File::C, Rank::Six)).unwrap();
+    assert_eq!(game.get_halfmove_clock(), 2);
+    assert_eq!(game.get_fullmove_number(), 2);
+
+    // Pawn moves reset the clock
+    game.move_piece(&BoardPosition::new(File::E, Rank::Two), &BoardPosition::new(File::E, Rank::Four)).unwrap();
+    assert_eq!(game.get_halfmove_clock(), 0);
+
+    game.move_piece(&BoardPosition::new(File::C, Rank::Six), &BoardPosition::new(File::D, Rank::Four)).unwrap();
+    assert_eq!(game.get_halfmove_clock(), 1);
+
+    // Captures reset the clock
+    game.move_piece(&BoardPosition::new(File::F, Rank::Three), &BoardPosition::new(File::D, Rank::Four)).unwrap();
+    assert_eq!(game.get_halfmove_clock(), 0);
+    assert_eq!(game.get_fullmove_number(), 3);
+}
+
+#[test]
+fn test_fifty_move_rule_can_be_claimed() {
+    let mut game = setup_rook_tour(99);
+    assert_eq!(game.get_halfmove_clock(), 99);
+    assert_eq!(game.claim_draw(), Err(ChessError::NoDrawToClaim));
+
+    let mut game = setup_rook_tour(100);
+    assert_eq!(game.get_game_state(), GameState::InProgress);
+    assert_eq!(game.claim_draw(), Ok(GameState::Draw(DrawReason::FiftyMoveRule)));
+}
+
+#[test]
+fn test_seventy_five_move_rule_ends_the_game() {
+    let game = setup_rook_tour(149);
+    assert_eq!(game.get_game_state(), GameState::InProgress);
+
+    let game = setup_rook_tour(150);
+    assert_eq!(game.get_game_state(), GameState::Draw(DrawReason::SeventyFiveMoveRule));
+}
+
+#[test]
+fn test_capturing_the_last_piece_is_a_draw() {
+    let mut game = Game::empty();
+    game.board.spawn_piece(Piece::King(Color::White), &BoardPosition::new(File::E, Rank::One).into()).unwrap();
+    game.board.spawn_piece(Piece::King(Color::Black), &BoardPosition::new(File::E, Rank::Eight).into()).unwrap();
+    game.board.spawn_piece(Piece::Knight(Color::White), &BoardPosition::new(File::B, Rank::One).into()).unwrap();
+    game.board.spawn_piece(Piece::Rook(Color::Black), &BoardPosition::new(File::C, Rank::Three).into()).unwrap();
+
+    let result = game.move_piece(&BoardPosition::new(File::B, Rank::One), &BoardPosition::new(File::C, Rank::Three));
+
+    assert_eq!(result, Ok(GameState::Draw(DrawReason::InsufficientMaterial)));
+    assert!(game.is_game_over());
+}
+
+#[test]
+fn test_opposite_colored_bishops_are_not_a_draw() {
+    let mut game = Game::empty();
+    game.board.spawn_piece(Piece::King(Color::White), &BoardPosition::new(File::E, Rank::One).into()).unwrap();
+    game.board.spawn_piece(Piece::King(Color::Black), &BoardPosition::new(File::E, Rank::Eight).into()).unwrap();
+    game.board.spawn_piece(Piece::Bishop(Color::White), &BoardPosition::new(File::C, Rank::One).into()).unwrap();
+    game.board.spawn_piece(Piece::Bishop(Color::Black), &BoardPosition::new(File::C, Rank::Eight).into()).unwrap();
+
+    let result = game.move_piece(&BoardPosition::new(File::C, Rank::One), &BoardPosition::new(File::D, Rank::Two));
+
+    assert_eq!(result, Ok(GameState::InProgress));
+}
@@ -0,0 +1,163 @@
+use olle_chess::castling::*;
+use olle_chess::fen::*;
+use olle_chess::*;
+use position::*;
+
+const START_FEN: &str = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+
+#[test]
+fn test_starting_position_from_fen() {
+    let game = Game::from_fen(START_FEN).unwrap();
+    let start = Game::new();
+
+    assert_eq!(game.board.piece_array, start.board.piece_array);
+    assert_eq!(game.get_turn(), Color::White);
+    assert_eq!(game.get_castling_rights(), CastlingRights::all());
+    assert_eq!(game.get_en_passant(), None);
+    assert_eq!(game.get_halfmove_clock(), 0);
+    assert_eq!(game.get_fullmove_number(), 1);
+    assert_eq!(game.get_game_state(), GameState::InProgress);
+}
+
+#[test]
+fn test_all_fields_are_read() {
+    let mut game = Game::from_fen("rnbqkbnr/ppp1pppp/8/8/3pP3/8/PPPP1PPP/RNBQKB1R b Kq e3 3 12").unwrap();
+
+    assert_eq!(game.get_turn(), Color::Black);
+    assert_eq!(game.get_piece(&BoardPosition::new(File::D, Rank::Four)), Some(Piece::Pawn(Color::Black)));
+    assert_eq!(game.get_piece(&BoardPosition::new(File::G, Rank::One)), None);
+    assert!(game.get_castling_rights().has(Color::White, CastlingSide::Kingside));
+    assert!(!game.get_castling_rights().has(Color::White, CastlingSide::Queenside));
+    assert!(!game.get_castling_rights().has(Color::Black, CastlingSide::Kingside));
+    assert!(game.get_castling_rights().has(Color::Black, CastlingSide::Queenside));
+    assert_eq!(game.get_en_passant(), Some(BoardPosition::new(File::E, Rank::Three)));
+    assert_eq!(game.get_halfmove_clock(), 3);
+    assert_eq!(game.get_fullmove_number(), 12);
+
+    let result = game.move_piece(&BoardPosition::new(File::D, Rank::Four), &BoardPosition::new(File::E, Rank::Three));
+    assert!(result.is_ok());
+    assert_eq!(game.get_piece(&BoardPosition::new(File::E, Rank::Four)), None);
+}
+
+#[test]
+fn test_game_state_from_fen() {
+    let game = Game::from_fen("rnb1kbnr/pppp1ppp/8/4p3/6Pq/5P2/PPPPP2P/RNBQKBNR w KQkq - 1 3").unwrap();
+    assert_eq!(game.get_game_state(), GameState::Checkmate(Color::Black));
+}
+
+#[test]
+fn test_fen_errors_name_the_field() {
+    assert_eq!(Game::from_fen("8/8/8/8/8/8/8/8 w - -").err(), Some(FenError::WrongFieldCount(4)));
+    assert_eq!(
+        Game::from_fen("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP w KQkq - 0 1").err(),
+        Some(FenError::WrongRankCount(7))
+    );
+    assert_eq!(
+        Game::from_fen("rnbqkbnr/pppppppp/8/8/8/7/PPPPPPPP/RNBQKBNR w KQkq - 0 1").err(),
+        Some(FenError::WrongRankLength(Rank::Three))
+    );
+    assert_eq!(
+        Game::from_fen("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNRR w KQkq - 0 1").err(),
+        Some(FenError::WrongRankLength(Rank::One))
+    );
+    assert_eq!(
+        Game::from_fen("rnbqkbnr/pppppppp/8/8/4X3/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1").err(),
+        Some(FenError::InvalidCharacter { field: FenField::PiecePlacement, character: 'X' })
+    );
+    assert_eq!(
+        Game::from_fen("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR W KQkq - 0 1").err(),
+        Some(FenError::InvalidCharacter { field: FenField::SideToMove, character: 'W' })
+    );
+    assert_eq!(
+        Game::from_fen("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQxq - 0 1").err(),
+        Some(FenError::InvalidCharacter { field: FenField::CastlingRights, character: 'x' })
+    );
+    assert_eq!(
+        Game::from_fen("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq e9 0 1").err(),
+        Some(FenError::InvalidCharacter { field: FenField::EnPassant, character: '9' })
+    );
+    assert_eq!(
+        Game::from_fen("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - -1 1").err(),
+        Some(FenError::InvalidCharacter { field: FenField::HalfmoveClock, character: '-' })
+    );
+    assert_eq!(
+        Game::from_fen("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1x").err(),
+        Some(FenError::InvalidCharacter { field: FenField::FullmoveNumber, character: 'x' })
+    );
+    assert_eq!(
+        Game::from_fen("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 99999999999999999999999 1").err(),
+        Some(FenError::NumberTooLarge(FenField::HalfmoveClock))
+    );
+    assert_eq!(
+        Game::from_fen("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 99999999999999999999999").err(),
+        Some(FenError::NumberTooLarge(FenField::FullmoveNumber))
+    );
+    assert_eq!(
+        Game::from_fen("4k3/8/8/8/8/8/8/4K3 w - - 0 18446744073709551615").err(),
+        Some(FenError::NumberTooLarge(FenField::FullmoveNumber))
+    );
+    assert_eq!(
+        Game::from_fen("4k3/8/8/8/8/8/8/4K2R w - - 18446744073709551615 1").err(),
+        Some(FenError::NumberTooLarge(FenField::HalfmoveClock))
+    );
+}
+
+#[test]
+fn test_impossible_fen_position() {
+    assert_eq!(
+        Game::from_fen("8/8/8/8/8/8/8/4K3 w - - 0 1").err(),
+        Some(FenError::InvalidPosition(ChessError::InvalidPosition))
+    );
+    assert_eq!(
+        Game::from_fen("4k3/8/8/8/8/8/8/4K3 w K - 0 1").err(),
+        Some(FenError::InvalidPosition(ChessError::InvalidCastlingRights))
+    );
+    assert_eq!(
+        Game::from_fen("4k3/8/8/8/8/8/8/4K3 w - - 0 0").err(),
+        Some(FenError::InvalidPosition(ChessError::InvalidMoveNumber))
+    );
+}
+
+#[test]
+fn test_starting_position_to_fen() {
+    let game = Game::new();
+    assert_eq!(game.to_fen(), START_FEN);
+}
+
+#[test]
+fn test_fen_after_moves() {
+    let mut game = Game::new();
+    game.move_piece(&BoardPosition::new(File::E, Rank::Two), &BoardPosition::new(File::E, Rank::Four)).unwrap();
+    assert_eq!(game.to_fen(), "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1");
+
+    game.move_piece(&BoardPosition::new(File::G, Rank::Eight), &BoardPosition::new(File::F, Rank::Six)).unwrap();
+    game.move_piece(&BoardPosition::new(File::E, Rank::One), &BoardPosition::new(File::E, Rank::Two)).unwrap();
+    assert_eq!(game.to_fen(), "rnbqkb1r/pppppppp/5n2/8/4P3/8/PPPPKPPP/RNBQ1BNR b kq - 2 2");
+}
+
+#[test]
+fn test_fen_round_trip() {
+    for fen in [
+        START_FEN,
+        "rnbqkbnr/ppp1pppp/8/8/3pP3/8/PPPP1PPP/RNBQKB1R b Kq e3 3 12",
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+        "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1",
+        "4k3/8/8/8/8/8/8/4K3 b - - 99 120",
+    ] {
+        assert_eq!(Game::from_fen(fen).unwrap().to_fen(), fen);
+    }
+}
+
+#[test]
+fn test_fen_while_waiting_for_promotion() {
+    let fen = "1n2k3/P7/8/8/8/8/8/4K3 w - - 0 1";
+    let mut game = Game::from_fen(fen).unwrap();
+    game.move_piece(&BoardPosition::new(File::A, Rank::Seven), &BoardPosition::new(File::B, Rank::Eight)).unwrap();
+    assert_eq!(game.get_game_state(), GameState::Promotion(BoardPosition::new(File::B, Rank::Eight)));
+
+    assert_eq!(game.to_fen(), fen);
+    assert!(Game::from_fen(&game.to_fen()).is_ok());
+
+    game.promote_pawn(Piece::Queen(Color::White)).unwrap();
+    assert_eq!(game.to_fen(), "1Q2k3/8/8/8/8/8/8/4K3 b - - 0 1");
+}
@@ -0,0 +1,136 @@
+use olle_chess::board::*;
+use olle_chess::castling::*;
+use olle_chess::*;
+use position::*;
+
+fn board_with_kings() -> Board {
+    let mut board = Board::new();
+    board.spawn_piece(Piece::King(Color::White), &BoardPosition::new(File::E, Rank::One).into()).unwrap();
+    board.spawn_piece(Piece::King(Color::Black), &BoardPosition::new(File::E, Rank::Eight).into()).unwrap();
+    board
+}
+
+#[test]
+fn test_black_to_move() {
+    let mut board = board_with_kings();
+    board.spawn_piece(Piece::Rook(Color::Black), &BoardPosition::new(File::A, Rank::Eight).into()).unwrap();
+    board.spawn_piece(Piece::Pawn(Color::White), &BoardPosition::new(File::D, Rank::Two).into()).unwrap();
+    board.spawn_piece(Piece::Pawn(Color::White), &BoardPosition::new(File::E, Rank::Two).into()).unwrap();
+    board.spawn_piece(Piece::Pawn(Color::White), &BoardPosition::new(File::F, Rank::Two).into()).unwrap();
+    board.spawn_piece(Piece::Rook(Color::White), &BoardPosition::new(File::D, Rank::One).into()).unwrap();
+
+    let mut game = Game::from_parts(board, Color::Black, CastlingRights::none(), None, 3, 20).unwrap();
+    assert_eq!(game.get_turn(), Color::Black);
+    assert_eq!(game.get_fullmove_number(), 20);
+    assert_eq!(game.get_halfmove_clock(), 3);
+    assert_eq!(game.get_game_state(), GameState::InProgress);
+
+    //The rook on d1 blocks the back rank, so there is no check
+    let result = game.move_piece(&BoardPosition::new(File::A, Rank::Eight), &BoardPosition::new(File::A, Rank::One));
+    assert_eq!(result, Ok(GameState::InProgress));
+    assert_eq!(game.get_fullmove_number(), 21);
+}
+
+#[test]
+fn test_game_state_is_computed() {
+    let mut board = Board::new();
+    board.spawn_piece(Piece::King(Color::White), &BoardPosition::new(File::H, Rank::One).into()).unwrap();
+    board.spawn_piece(Piece::King(Color::Black), &BoardPosition::new(File::H, Rank::Three).into()).unwrap();
+    board.spawn_piece(Piece::Queen(Color::Black), &BoardPosition::new(File::G, Rank::Two).into()).unwrap();
+    let game = Game::from_parts(board, Color::White, CastlingRights::none(), None, 0, 40).unwrap();
+    assert_eq!(game.get_game_state(), GameState::Checkmate(Color::Black));
+
+    let mut board = Board::new();
+    board.spawn_piece(Piece::King(Color::White), &BoardPosition::new(File::H, Rank::One).into()).unwrap();
+    board.spawn_piece(Piece::King(Color::Black), &BoardPosition::new(File::F, Rank::Two).into()).unwrap();
+    board.spawn_piece(Piece::Queen(Color::Black), &BoardPosition::new(File::G, Rank::Three).into()).unwrap();
+    let game = Game::from_parts(board, Color::White, CastlingRights::none(), None, 0, 40).unwrap();
+    assert_eq!(game.get_game_state(), GameState::Stalemate);
+
+    let game = Game::from_parts(board_with_kings(), Color::White, CastlingRights::none(), None, 0, 1).unwrap();
+    assert_eq!(game.get_game_state(), GameState::Draw(DrawReason::InsufficientMaterial));
+}
+
+#[test]
+fn test_kings_are_required() {
+    let mut board = Board::new();
+    board.spawn_piece(Piece::King(Color::White), &BoardPosition::new(File::E, Rank::One).into()).unwrap();
+    let result = Game::from_parts(board, Color::White, CastlingRights::none(), None, 0, 1);
+    assert_eq!(result.err(), Some(ChessError::InvalidPosition));
+
+    let mut board = board_with_kings();
+    board.spawn_piece(Piece::King(Color::White), &BoardPosition::new(File::A, Rank::One).into()).unwrap();
+    let result = Game::from_parts(board, Color::White, CastlingRights::none(), None, 0, 1);
+    assert_eq!(result.err(), Some(ChessError::InvalidPosition));
+}
+
+#[test]
+fn test_side_not_to_move_cannot_be_in_check() {
+    let mut board = board_with_kings();
+    board.spawn_piece(Piece::Rook(Color::White), &BoardPosition::new(File::E, Rank::Five).into()).unwrap();
+
+    let result = Game::from_parts(board, Color::White, CastlingRights::none(), None, 0, 1);
+    assert_eq!(result.err(), Some(ChessError::InvalidPosition));
+}
+
+#[test]
+fn test_castling_rights_need_king_and_rook() {
+    let mut board = board_with_kings();
+    board.spawn_piece(Piece::Rook(Color::White), &BoardPosition::new(File::H, Rank::One).into()).unwrap();
+
+    let mut rights = CastlingRights::none();
+    rights.white_kingside = true;
+    let game = Game::from_parts(board, Color::White, rights, None, 0, 1).unwrap();
+    assert_eq!(game.get_castling_rights(), rights);
+
+    let mut board = board_with_kings();
+    board.spawn_piece(Piece::Rook(Color::White), &BoardPosition::new(File::H, Rank::One).into()).unwrap();
+    rights.white_queenside = true;
+    let result = Game::from_parts(board, Color::White, rights, None, 0, 1);
+    assert_eq!(result.err(), Some(ChessError::InvalidCastlingRights));
+}
+
+#[test]
+fn test_en_passant_square_is_checked() {
+    let mut board = board_with_kings();
+    board.spawn_piece(Piece::Pawn(Color::Black), &BoardPosition::new(File::D, Rank::Five).into()).unwrap();
+    board.spawn_piece(Piece::Pawn(Color::White), &BoardPosition::new(File::E, Rank::Five).into()).unwrap();
+
+    let mut game = Game::from_parts(board, Color::White, CastlingRights::none(), Some(BoardPosition::new(File::D, Rank::Six)), 0, 1).unwrap();
+    let moves = game.get_possible_moves(&BoardPosition::new(File::E, Rank::Five)).unwrap();
+    assert!(moves.contains(&BoardPosition::new(File::D, Rank::Six)));
+
+    let mut board = board_with_kings();
+    board.spawn_piece(Piece::Pawn(Color::Black), &BoardPosition::new(File::D, Rank::Five).into()).unwrap();
+    for square in [BoardPosition::new(File::D, Rank::Three), BoardPosition::new(File::C, Rank::Six)] {
+        let result = Game::from_parts(board_with_kings(), Color::White, CastlingRights::none(), Some(square), 0, 1);
+        assert_eq!(result.err(), Some(ChessError::InvalidEnPassant));
+    }
+    let result = Game::from_parts(board, Color::Black, CastlingRights::none(), Some(BoardPosition::new(File::D, Rank::Six)), 0, 1);
+    assert_eq!(result.err(), Some(ChessError::InvalidEnPassant));
+}
+
+#[test]
+fn test_fullmove_number_starts_at_one() {
+    let result = Game::from_parts(board_with_kings(), Color::White, CastlingRights::none(), None, 0, 0);
+    assert_eq!(result.err(), Some(ChessError::InvalidMoveNumber));
+}
+
+#[test]
+fn test_counters_out_of_range() {
+    let result = Game::from_parts(board_with_kings(), Color::White, CastlingRights::none(), None, 0, usize::MAX);
+    assert_eq!(result.err(), Some(ChessError::InvalidMoveNumber));
+    let result = Game::from_parts(board_with_kings(), Color::Black, CastlingRights::none(), None, 0, usize::MAX / 2 + 1);
+    assert_eq!(result.err(), Some(ChessError::InvalidMoveNumber));
+    let game = Game::from_parts(board_with_kings(), Color::White, CastlingRights::none(), None, 0, usize::MAX / 2 + 1).unwrap();
+    assert_eq!(game.get_fullmove_number(), usize::MAX / 2 + 1);
+
+    let result = Game::from_parts(board_with_kings(), Color::White, CastlingRights::none(), None, usize::MAX, 1);
+    assert_eq!(result.err(), Some(ChessError::InvalidHalfmoveClock));
+    let result = Game::from_parts(board_with_kings(), Color::White, CastlingRights::none(), None, 151, 1);
+    assert_eq!(result.err(), Some(ChessError::InvalidHalfmoveClock));
+    let mut board = board_with_kings();
+    board.spawn_piece(Piece::Rook(Color::White), &BoardPosition::new(File::A, Rank::One).into()).unwrap();
+    let game = Game::from_parts(board, Color::White, CastlingRights::none(), None, 150, 1).unwrap();
+    assert_eq!(game.get_game_state(), GameState::Draw(DrawReason::SeventyFiveMoveRule));
+}
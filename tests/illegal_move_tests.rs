@@ -0,0 +1,49 @@
+use olle_chess::*;
+use position::*;
+
+fn illegal_move_reason(game: &mut Game, from: BoardPosition, to: BoardPosition) -> IllegalMoveReason {
+    match game.move_piece(&from, &to) {
+        Err(ChessError::IllegalMove(reason)) => reason,
+        result => panic!("expected an illegal move, got {:?}", result),
+    }
+}
+
+#[test]
+fn test_move_outside_the_pattern() {
+    let mut game = Game::new();
+    let reason = illegal_move_reason(&mut game, BoardPosition::new(File::G, Rank::One), BoardPosition::new(File::G, Rank::Three));
+    assert_eq!(reason, IllegalMoveReason::NotInPattern);
+
+    let reason = illegal_move_reason(&mut game, BoardPosition::new(File::E, Rank::Two), BoardPosition::new(File::E, Rank::Five));
+    assert_eq!(reason, IllegalMoveReason::NotInPattern);
+}
+
+#[test]
+fn test_path_is_blocked() {
+    let mut game = Game::new();
+    let reason = illegal_move_reason(&mut game, BoardPosition::new(File::A, Rank::One), BoardPosition::new(File::A, Rank::Five));
+    assert_eq!(reason, IllegalMoveReason::PathBlocked);
+
+    //Capturing your own piece is blocked too
+    let reason = illegal_move_reason(&mut game, BoardPosition::new(File::D, Rank::One), BoardPosition::new(File::D, Rank::Two));
+    assert_eq!(reason, IllegalMoveReason::PathBlocked);
+}
+
+#[test]
+fn test_pawn_needs_something_to_capture() {
+    let mut game = Game::new();
+    let reason = illegal_move_reason(&mut game, BoardPosition::new(File::E, Rank::Two), BoardPosition::new(File::F, Rank::Three));
+    assert_eq!(reason, IllegalMoveReason::NothingToCapture);
+}
+
+#[test]
+fn test_pinned_piece_leaves_king_in_check() {
+    let mut game = Game::empty();
+    game.board.spawn_piece(Piece::King(Color::White), &BoardPosition::new(File::E, Rank::One).into()).unwrap();
+    game.board.spawn_piece(Piece::King(Color::Black), &BoardPosition::new(File::A, Rank::Eight).into()).unwrap();
+    game.board.spawn_piece(Piece::Knight(Color::White), &BoardPosition::new(File::E, Rank::Three).into()).unwrap();
+    game.board.spawn_piece(Piece::Rook(Color::Black), &BoardPosition::new(File::E, Rank::Seven).into()).unwrap();
+
+    let reason = illegal_move_reason(&mut game, BoardPosition::new(File::E, Rank::Three), BoardPosition::new(File::F, Rank::Five));
+    assert_eq!(reason, IllegalMoveReason::LeavesKingInCheck);
+}
@@ -0,0 +1,200 @@
+use olle_chess::castling::*;
+use olle_chess::chess_move::*;
+use olle_chess::*;
+use position::*;
+
+fn setup_empty_with_kings() -> Game {
+    let mut game = Game::empty();
+    game.board.spawn_piece(Piece::King(Color::White), &BoardPosition::new(File::E, Rank::One).into()).unwrap();
+    game.board.spawn_piece(Piece::King(Color::Black), &BoardPosition::new(File::E, Rank::Eight).into()).unwrap();
+    game
+}
+
+#[test]
+fn test_starting_position_has_twenty_legal_moves() {
+    let mut game = Game::new();
+    let moves = game.legal_moves();
+
+    assert_eq!(moves.len(), 20);
+    assert!(moves.contains(&Move::new(
+        BoardPosition::new(File::E, Rank::Two),
+        BoardPosition::new(File::E, Rank::Four),
+        None,
+        MoveKind::DoubleStep
+    )));
+    assert!(moves.contains(&Move::new(
+        BoardPosition::new(File::G, Rank::One),
+        BoardPosition::new(File::F, Rank::Three),
+        None,
+        MoveKind::Quiet
+    )));
+}
+
+#[test]
+fn test_legal_moves_are_for_the_side_to_move() {
+    let mut game = Game::new();
+    game.move_piece(&BoardPosition::new(File::E, Rank::Two), &BoardPosition::new(File::E, Rank::Four)).unwrap();
+
+    let moves = game.legal_moves();
+    assert_eq!(moves.len(), 20);
+    assert!(moves.iter().all(|m| game.get_piece(&m.from).unwrap().get_color() == Color::Black));
+}
+
+#[test]
+fn test_promotions_are_listed_for_every_piece() {
+    let mut game = setup_empty_with_kings();
+    game.board.spawn_piece(Piece::Pawn(Color::White), &BoardPosition::new(File::A, Rank::Seven).into()).unwrap();
+    game.board.spawn_piece(Piece::Rook(Color::Black), &BoardPosition::new(File::B, Rank::Eight).into()).unwrap();
+
+    let moves: Vec<Move> = game
+        .legal_moves()
+        .into_iter()
+        .filter(|m| m.from == BoardPosition::new(File::A, Rank::Seven))
+        .collect();
+
+    assert_eq!(moves.len(), 8);
+    assert!(moves.iter().all(|m| m.is_promotion()));
+    assert!(moves.contains(&Move::new(
+        BoardPosition::new(File::A, Rank::Seven),
+        BoardPosition::new(File::B, Rank::Eight),
+        Some(PieceKind::Knight),
+        MoveKind::Capture
+    )));
+    assert!(moves.contains(&Move::new(
+        BoardPosition::new(File::A, Rank::Seven),
+        BoardPosition::new(File::A, Rank::Eight),
+        Some(PieceKind::Queen),
+        MoveKind::Quiet
+    )));
+}
+
+#[test]
+fn test_castling_and_en_passant_kinds() {
+    let mut game = Game::new();
+    game.board.despawn_piece(&BoardPosition::new(File::F, Rank::One).into());
+    game.board.despawn_piece(&BoardPosition::new(File::G, Rank::One).into());
+    game.move_piece(&BoardPosition::new(File::E, Rank::Two), &BoardPosition::new(File::E, Rank::Four)).unwrap();
+    game.move_piece(&BoardPosition::new(File::A, Rank::Seven), &BoardPosition::new(File::A, Rank::Six)).unwrap();
+    game.move_piece(&BoardPosition::new(File::E, Rank::Four), &BoardPosition::new(File::E, Rank::Five)).unwrap();
+    game.move_piece(&BoardPosition::new(File::F, Rank::Seven), &BoardPosition::new(File::F, Rank::Five)).unwrap();
+
+    let moves = game.legal_moves();
+    let castle = moves.iter().find(|m| m.is_castle()).unwrap();
+    assert_eq!(castle.kind, MoveKind::Castle(CastlingSide::Kingside));
+    assert_eq!(castle.to, BoardPosition::new(File::G, Rank::One));
+
+    let en_passant = moves.iter().find(|m| m.kind == MoveKind::EnPassant).unwrap();
+    assert_eq!(en_passant.from, BoardPosition::new(File::E, Rank::Five));
+    assert_eq!(en_passant.to, BoardPosition::new(File::F, Rank::Six));
+    assert!(en_passant.is_capture());
+}
+
+#[test]
+fn test_no_legal_moves_after_checkmate() {
+    let mut game = Game::new();
+    game.move_piece(&BoardPosition::new(File::F, Rank::Two), &BoardPosition::new(File::F, Rank::Three)).unwrap();
+    game.move_piece(&BoardPosition::new(File::E, Rank::Seven), &BoardPosition::new(File::E, Rank::Five)).unwrap();
+    game.move_piece(&BoardPosition::new(File::G, Rank::Two), &BoardPosition::new(File::G, Rank::Four)).unwrap();
+    game.move_piece(&BoardPosition::new(File::D, Rank::Eight), &BoardPosition::new(File::H, Rank::Four)).unwrap();
+
+    assert!(game.legal_moves().is_empty());
+}
+
+fn perft(game: &mut Game, depth: usize) -> usize {
+    if depth == 0 {
+        return 1;
+    }
+    let mut nodes = 0;
+    for m in game.legal_moves() {
+        let undo = game.make_move(m);
+        nodes += perft(game, depth - 1);
+        game.unmake_move(undo);
+    }
+    nodes
+}
+
+fn assert_same_game(game: &Game, other: &Game) {
+    assert_eq!(game.board.piece_array, other.board.piece_array);
+    assert_eq!(game.board.get_king_position(Color::White), other.board.get_king_position(Color::White));
+    assert_eq!(game.board.get_king_position(Color::Black), other.board.get_king_position(Color::Black));
+    assert_eq!(game.get_turn(), other.get_turn());
+    assert_eq!(game.get_castling_rights(), other.get_castling_rights());
+    assert_eq!(game.get_en_passant(), other.get_en_passant());
+    assert_eq!(game.get_halfmove_clock(), other.get_halfmove_clock());
+    assert_eq!(game.get_fullmove_number(), other.get_fullmove_number());
+    assert_eq!(game.get_game_state(), other.get_game_state());
+}
+
+#[test]
+fn test_perft_from_starting_position() {
+    let mut game = Game::new();
+    assert_eq!(perft(&mut game, 1), 20);
+    assert_eq!(perft(&mut game, 2), 400);
+    assert_eq!(perft(&mut game, 3), 8902);
+}
+
+#[test]
+fn test_unmake_move_restores_the_game() {
+    let mut game = Game::new();
+    let before = Game::new();
+    for m in game.legal_moves() {
+        let undo = game.make_move(m);
+        assert_eq!(game.get_turn(), Color::Black);
+        game.unmake_move(undo);
+        assert_same_game(&game, &before);
+    }
+}
+
+#[test]
+fn test_unmake_castling_en_passant_and_promotion() {
+    let mut game = Game::new();
+    game.board.despawn_piece(&BoardPosition::new(File::F, Rank::One).into());
+    game.board.despawn_piece(&BoardPosition::new(File::G, Rank::One).into());
+    game.board.despawn_piece(&BoardPosition::new(File::A, Rank::Eight).into());
+    game.board.set_piece(Piece::Pawn(Color::White), &BoardPosition::new(File::A, Rank::Seven).into());
+    game.move_piece(&BoardPosition::new(File::E, Rank::Two), &BoardPosition::new(File::E, Rank::Four)).unwrap();
+    game.move_piece(&BoardPosition::new(File::H, Rank::Seven), &BoardPosition::new(File::H, Rank::Six)).unwrap();
+    game.move_piece(&BoardPosition::new(File::E, Rank::Four), &BoardPosition::new(File::E, Rank::Five)).unwrap();
+    game.move_piece(&BoardPosition::new(File::F, Rank::Seven), &BoardPosition::new(File::F, Rank::Five)).unwrap();
+
+    let piece_array = game.board.piece_array;
+    let moves = game.legal_moves();
+    let special: Vec<&Move> = moves.iter().filter(|m| m.is_castle() || m.kind == MoveKind::EnPassant || m.is_promotion()).collect();
+    assert_eq!(special.len(), 10);
+
+    let castling_rights = game.get_castling_rights();
+    let en_passant = game.get_en_passant();
+    for m in special {
+        let undo = game.make_move(*m);
+        assert_eq!(undo.get_move(), *m);
+        game.unmake_move(undo);
+        assert_eq!(game.board.piece_array, piece_array);
+        assert_eq!(game.board.get_king_position(Color::White), Some(BoardPosition::new(File::E, Rank::One).into()));
+        assert_eq!(game.get_castling_rights(), castling_rights);
+        assert_eq!(game.get_en_passant(), en_passant);
+        assert_eq!(game.get_turn(), Color::White);
+    }
+}
+
+#[test]
+fn test_make_move_does_not_check_for_game_end() {
+    let mut game = Game::new();
+    let undo = game.make_move(Move::new(BoardPosition::new(File::G, Rank::One), BoardPosition::new(File::F, Rank::Three), None, MoveKind::Quiet));
+
+    assert_eq!(game.get_turn(), Color::Black);
+    assert_eq!(game.get_halfmove_clock(), 1);
+    assert_eq!(game.get_game_state(), GameState::InProgress);
+    game.unmake_move(undo);
+    assert_eq!(game.get_halfmove_clock(), 0);
+    assert_eq!(game.get_piece(&BoardPosition::new(File::G, Rank::One)), Some(Piece::Knight(Color::White)));
+}
+
+#[test]
+fn test_perft_with_castling_en_passant_and_promotions() {
+    let mut game = Game::from_fen("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1").unwrap();
+    assert_eq!(perft(&mut game, 1), 48);
+    assert_eq!(perft(&mut game, 2), 2039);
+
+    let mut game = Game::from_fen("8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1").unwrap();
+    assert_eq!(perft(&mut game, 3), 2812);
+}
@@ -7,7 +7,7 @@ fn setup_empty_with_kings() -> Game {
    let mut game = Game::empty();
    game.board.spawn_piece(Piece::King(Color::White), &BoardPosition::new(File::E, Rank::One).into()).unwrap();
    game.board.spawn_piece(Piece::King(Color::Black), &BoardPosition::new(File::E, Rank::Eight).into()).unwrap();
-   return game;
+   game
 }
 
 #[test]
@@ -20,25 +20,86 @@ fn test_turn_starts_as_white() {
 fn test_game_is_won_by_white() {
    let mut game = Game::empty();
    game.board.spawn_piece(Piece::King(Color::White), &BoardPosition::new(File::E, Rank::One).into()).unwrap();
+   game.board.spawn_piece(Piece::Rook(Color::White), &BoardPosition::new(File::A, Rank::One).into()).unwrap();
+   game.board.spawn_piece(Piece::King(Color::Black), &BoardPosition::new(File::H, Rank::Eight).into()).unwrap();
+   game.board.spawn_piece(Piece::Pawn(Color::Black), &BoardPosition::new(File::G, Rank::Seven).into()).unwrap();
+   game.board.spawn_piece(Piece::Pawn(Color::Black), &BoardPosition::new(File::H, Rank::Seven).into()).unwrap();
 
-   let _ = game.move_piece(&BoardPosition::new(File::E, Rank::One), &BoardPosition::new(File::E, Rank::Two));
+   //Back rank mate
+   let result = game.move_piece(&BoardPosition::new(File::A, Rank::One), &BoardPosition::new(File::A, Rank::Eight));
    
-   assert_eq!(game.get_game_state(), GameState::GameOver(Color::White));
+   assert_eq!(result, Ok(GameState::Checkmate(Color::White)));
+   assert_eq!(game.get_game_state(), GameState::Checkmate(Color::White));
 }
 
 #[test]
 fn test_game_is_won_by_black() {
    let mut game = Game::empty();
    game.board.spawn_piece(Piece::King(Color::Black), &BoardPosition::new(File::E, Rank::Eight).into()).unwrap();
-   game.board.spawn_piece(Piece::Pawn(Color::White), &BoardPosition::new(File::A, Rank::Five).into()).unwrap();
+   game.board.spawn_piece(Piece::Rook(Color::Black), &BoardPosition::new(File::A, Rank::Eight).into()).unwrap();
+   game.board.spawn_piece(Piece::King(Color::White), &BoardPosition::new(File::H, Rank::One).into()).unwrap();
+   game.board.spawn_piece(Piece::Pawn(Color::White), &BoardPosition::new(File::G, Rank::Two).into()).unwrap();
+   game.board.spawn_piece(Piece::Pawn(Color::White), &BoardPosition::new(File::H, Rank::Two).into()).unwrap();
+   game.board.spawn_piece(Piece::Pawn(Color::White), &BoardPosition::new(File::B, Rank::Five).into()).unwrap();
 
    //Change turn to black
-   let _ = game.move_piece(&BoardPosition::new(File::A, Rank::Five), &BoardPosition::new(File::A, Rank::Six));
+   game.move_piece(&BoardPosition::new(File::B, Rank::Five), &BoardPosition::new(File::B, Rank::Six)).unwrap();
 
-   //Move black king to win the game
-   let _ = game.move_piece(&BoardPosition::new(File::E, Rank::Eight), &BoardPosition::new(File::E, Rank::Seven));
+   //Back rank mate
+   game.move_piece(&BoardPosition::new(File::A, Rank::Eight), &BoardPosition::new(File::A, Rank::One)).unwrap();
    
-   assert_eq!(game.get_game_state(), GameState::GameOver(Color::Black));
+   assert_eq!(game.get_game_state(), GameState::Checkmate(Color::Black));
+}
+
+#[test]
+fn test_fools_mate() {
+   let mut game = Game::new();
+   game.move_piece(&BoardPosition::new(File::F, Rank::Two), &BoardPosition::new(File::F, Rank::Three)).unwrap();
+   game.move_piece(&BoardPosition::new(File::E, Rank::Seven), &BoardPosition::new(File::E, Rank::Five)).unwrap();
+   game.move_piece(&BoardPosition::new(File::G, Rank::Two), &BoardPosition::new(File::G, Rank::Four)).unwrap();
+   let result = game.move_piece(&BoardPosition::new(File::D, Rank::Eight), &BoardPosition::new(File::H, Rank::Four));
+
+   assert_eq!(result, Ok(GameState::Checkmate(Color::Black)));
+}
+
+#[test]
+fn test_check_that_can_be_answered_is_not_checkmate() {
+   let mut game = setup_empty_with_kings();
+   game.board.spawn_piece(Piece::Rook(Color::White), &BoardPosition::new(File::A, Rank::One).into()).unwrap();
+   game.board.spawn_piece(Piece::Pawn(Color::Black), &BoardPosition::new(File::D, Rank::Seven).into()).unwrap();
+   game.board.spawn_piece(Piece::Pawn(Color::Black), &BoardPosition::new(File::E, Rank::Seven).into()).unwrap();
+   game.board.spawn_piece(Piece::Pawn(Color::Black), &BoardPosition::new(File::F, Rank::Seven).into()).unwrap();
+   //The bishop can capture the checking rook
+   game.board.spawn_piece(Piece::Bishop(Color::Black), &BoardPosition::new(File::B, Rank::Seven).into()).unwrap();
+
+   let result = game.move_piece(&BoardPosition::new(File::A, Rank::One), &BoardPosition::new(File::A, Rank::Eight));
+
+   assert_eq!(result, Ok(GameState::Check));
+}
+
+#[test]
+fn test_game_over_after_checkmate() {
+   let mut game = Game::new();
+   game.move_piece(&BoardPosition::new(File::F, Rank::Two), &BoardPosition::new(File::F, Rank::Three)).unwrap();
+   game.move_piece(&BoardPosition::new(File::E, Rank::Seven), &BoardPosition::new(File::E, Rank::Five)).unwrap();
+   game.move_piece(&BoardPosition::new(File::G, Rank::Two), &BoardPosition::new(File::G, Rank::Four)).unwrap();
+   game.move_piece(&BoardPosition::new(File::D, Rank::Eight), &BoardPosition::new(File::H, Rank::Four)).unwrap();
+
+   assert!(game.is_game_over());
+   let result = game.move_piece(&BoardPosition::new(File::A, Rank::Two), &BoardPosition::new(File::A, Rank::Three));
+   assert_eq!(result, Err(ChessError::GameAlreadyOver));
+}
+
+#[test]
+fn test_king_cannot_be_captured() {
+   let mut game = setup_empty_with_kings();
+   game.board.spawn_piece(Piece::Queen(Color::White), &BoardPosition::new(File::E, Rank::Four).into()).unwrap();
+
+   let moves = game.get_possible_moves(&BoardPosition::new(File::E, Rank::Four)).unwrap();
+   assert!(!moves.contains(&BoardPosition::new(File::E, Rank::Eight)));
+
+   let result = game.move_piece(&BoardPosition::new(File::E, Rank::Four), &BoardPosition::new(File::E, Rank::Eight));
+   assert_eq!(result, Err(ChessError::IllegalMove(IllegalMoveReason::PathBlocked)));
 }
 
 #[test]
@@ -107,7 +168,7 @@ fn test_white_cannot_put_themselves_in_check() {
    let result = game.move_piece(&BoardPosition::new(File::E, Rank::One), &BoardPosition::new(File::F, Rank::One));
    
    assert!(result.is_err());
-   assert_eq!(result.err().unwrap(), ChessError::IllegalMove);
+   assert_eq!(result.err().unwrap(), ChessError::IllegalMove(IllegalMoveReason::LeavesKingInCheck));
 }
 
 #[test]
@@ -120,7 +181,7 @@ fn test_black_cannot_put_themselves_in_check() {
    let result = game.move_piece(&BoardPosition::new(File::E, Rank::Eight), &BoardPosition::new(File::F, Rank::Eight));
 
    assert!(result.is_err());
-   assert_eq!(result.err().unwrap(), ChessError::IllegalMove);
+   assert_eq!(result.err().unwrap(), ChessError::IllegalMove(IllegalMoveReason::LeavesKingInCheck));
 }
 
 #[test]
@@ -133,4 +194,31 @@ fn test_game_is_in_progress_when_moving_out_of_check() {
    game.move_piece(&BoardPosition::new(File::E, Rank::Eight), &BoardPosition::new(File::E, Rank::Seven)).unwrap();
    
    assert_eq!(game.get_game_state(), GameState::InProgress);
-}
\ No newline at end of file
+}
+#[test]
+fn test_stalemate() {
+   let mut game = Game::empty();
+   game.board.spawn_piece(Piece::King(Color::White), &BoardPosition::new(File::F, Rank::Seven).into()).unwrap();
+   game.board.spawn_piece(Piece::Queen(Color::White), &BoardPosition::new(File::G, Rank::Five).into()).unwrap();
+   game.board.spawn_piece(Piece::King(Color::Black), &BoardPosition::new(File::H, Rank::Eight).into()).unwrap();
+
+   let result = game.move_piece(&BoardPosition::new(File::G, Rank::Five), &BoardPosition::new(File::G, Rank::Six));
+
+   assert_eq!(result, Ok(GameState::Stalemate));
+   assert!(game.is_game_over());
+   let result = game.move_piece(&BoardPosition::new(File::H, Rank::Eight), &BoardPosition::new(File::G, Rank::Eight));
+   assert_eq!(result, Err(ChessError::GameAlreadyOver));
+}
+
+#[test]
+fn test_no_stalemate_while_a_pawn_can_move() {
+   let mut game = Game::empty();
+   game.board.spawn_piece(Piece::King(Color::White), &BoardPosition::new(File::F, Rank::Seven).into()).unwrap();
+   game.board.spawn_piece(Piece::Queen(Color::White), &BoardPosition::new(File::G, Rank::Five).into()).unwrap();
+   game.board.spawn_piece(Piece::King(Color::Black), &BoardPosition::new(File::H, Rank::Eight).into()).unwrap();
+   game.board.spawn_piece(Piece::Pawn(Color::Black), &BoardPosition::new(File::A, Rank::Seven).into()).unwrap();
+
+   let result = game.move_piece(&BoardPosition::new(File::G, Rank::Five), &BoardPosition::new(File::G, Rank::Six));
+
+   assert_eq!(result, Ok(GameState::InProgress));
+}
@@ -0,0 +1,106 @@
+use olle_chess::outcome::*;
+use olle_chess::*;
+use position::*;
+
+#[test]
+fn test_no_outcome_while_in_progress() {
+    let game = Game::new();
+    assert_eq!(game.get_outcome(), None);
+}
+
+#[test]
+fn test_resignation() {
+    let mut game = Game::new();
+    assert_eq!(game.resign(Color::White), Ok(GameState::Resigned(Color::White)));
+    assert_eq!(
+        game.get_outcome(),
+        Some(GameOutcome::new(GameResult::Win(Color::Black), GameOverReason::Resignation))
+    );
+
+    let result = game.move_piece(&BoardPosition::new(File::E, Rank::Two), &BoardPosition::new(File::E, Rank::Four));
+    assert_eq!(result, Err(ChessError::GameAlreadyOver));
+    assert_eq!(game.resign(Color::Black), Err(ChessError::GameAlreadyOver));
+}
+
+#[test]
+fn test_draw_by_agreement() {
+    let mut game = Game::new();
+    game.offer_draw(Color::White).unwrap();
+    assert_eq!(game.get_draw_offer(), Some(Color::White));
+
+    // A player cannot accept their own offer
+    assert_eq!(game.accept_draw(Color::White), Err(ChessError::NoDrawOffer));
+
+    assert_eq!(game.accept_draw(Color::Black), Ok(GameState::Draw(DrawReason::Agreement)));
+    assert_eq!(
+        game.get_outcome(),
+        Some(GameOutcome::new(GameResult::Draw, GameOverReason::Agreement))
+    );
+}
+
+#[test]
+fn test_draw_offer_can_be_declined() {
+    let mut game = Game::new();
+    assert_eq!(game.decline_draw(Color::Black), Err(ChessError::NoDrawOffer));
+
+    game.offer_draw(Color::White).unwrap();
+    game.decline_draw(Color::Black).unwrap();
+
+    assert_eq!(game.get_draw_offer(), None);
+    assert_eq!(game.accept_draw(Color::Black), Err(ChessError::NoDrawOffer));
+    assert_eq!(game.get_game_state(), GameState::InProgress);
+}
+
+#[test]
+fn test_draw_offer_is_declined_by_moving() {
+    let mut game = Game::new();
+    // White offers and then makes their move, the offer still stands
+    game.offer_draw(Color::White).unwrap();
+    game.move_piece(&BoardPosition::new(File::E, Rank::Two), &BoardPosition::new(File::E, Rank::Four)).unwrap();
+    assert_eq!(game.get_draw_offer(), Some(Color::White));
+
+    // Black answers with a move instead
+    game.move_piece(&BoardPosition::new(File::E, Rank::Seven), &BoardPosition::new(File::E, Rank::Five)).unwrap();
+    assert_eq!(game.get_draw_offer(), None);
+    assert_eq!(game.accept_draw(Color::Black), Err(ChessError::NoDrawOffer));
+}
+
+#[test]
+fn test_timeout() {
+    let mut game = Game::new();
+    assert_eq!(game.time_out(Color::Black), Ok(GameState::TimedOut(Color::Black)));
+    assert_eq!(
+        game.get_outcome(),
+        Some(GameOutcome::new(GameResult::Win(Color::White), GameOverReason::Timeout))
+    );
+}
+
+#[test]
+fn test_timeout_against_a_lone_king_is_a_draw() {
+    let mut game = Game::empty();
+    game.board.spawn_piece(Piece::King(Color::White), &BoardPosition::new(File::E, Rank::One).into()).unwrap();
+    game.board.spawn_piece(Piece::King(Color::Black), &BoardPosition::new(File::E, Rank::Eight).into()).unwrap();
+    game.board.spawn_piece(Piece::Queen(Color::Black), &BoardPosition::new(File::D, Rank::Eight).into()).unwrap();
+
+    assert_eq!(game.time_out(Color::Black), Ok(GameState::Draw(DrawReason::Timeout)));
+    assert_eq!(
+        game.get_outcome(),
+        Some(GameOutcome::new(GameResult::Draw, GameOverReason::Timeout))
+    );
+}
+
+#[test]
+fn test_checkmate_and_stalemate_outcomes() {
+    assert_eq!(
+        GameOutcome::from_state(GameState::Checkmate(Color::Black)),
+        Some(GameOutcome::new(GameResult::Win(Color::Black), GameOverReason::Checkmate))
+    );
+    assert_eq!(
+        GameOutcome::from_state(GameState::Stalemate),
+        Some(GameOutcome::new(GameResult::Draw, GameOverReason::Stalemate))
+    );
+    assert_eq!(
+        GameOutcome::from_state(GameState::Draw(DrawReason::FivefoldRepetition)),
+        Some(GameOutcome::new(GameResult::Draw, GameOverReason::Repetition))
+    );
+}
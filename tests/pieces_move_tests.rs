@@ -45,3 +45,148 @@ fn test_legal_moves_visualization() {
         print_legal_moves(piece, &position);
     }
 }
+
+fn setup_empty_with_kings() -> Game {
+    let mut game = Game::empty();
+    game.board.spawn_piece(Piece::King(Color::White), &BoardPosition::new(File::E, Rank::One).into()).unwrap();
+    game.board.spawn_piece(Piece::King(Color::Black), &BoardPosition::new(File::E, Rank::Eight).into()).unwrap();
+    game
+}
+
+#[test]
+fn test_pawn_can_double_step_from_start_rank() {
+    let mut game = Game::new();
+
+    let moves = game.get_possible_moves(&BoardPosition::new(File::D, Rank::Two)).unwrap();
+    assert_eq!(moves, vec![BoardPosition::new(File::D, Rank::Three), BoardPosition::new(File::D, Rank::Four)]);
+
+    game.move_piece(&BoardPosition::new(File::D, Rank::Two), &BoardPosition::new(File::D, Rank::Four)).unwrap();
+    game.move_piece(&BoardPosition::new(File::D, Rank::Seven), &BoardPosition::new(File::D, Rank::Five)).unwrap();
+
+    assert_eq!(game.get_piece(&BoardPosition::new(File::D, Rank::Four)), Some(Piece::Pawn(Color::White)));
+    assert_eq!(game.get_piece(&BoardPosition::new(File::D, Rank::Five)), Some(Piece::Pawn(Color::Black)));
+}
+
+#[test]
+fn test_pawn_cannot_double_step_outside_start_rank() {
+    let mut game = setup_empty_with_kings();
+    game.board.spawn_piece(Piece::Pawn(Color::White), &BoardPosition::new(File::D, Rank::Three).into()).unwrap();
+
+    let result = game.move_piece(&BoardPosition::new(File::D, Rank::Three), &BoardPosition::new(File::D, Rank::Five));
+    assert_eq!(result, Err(ChessError::IllegalMove(IllegalMoveReason::NotInPattern)));
+}
+
+#[test]
+fn test_pawn_double_step_is_blocked() {
+    let mut game = setup_empty_with_kings();
+    game.board.spawn_piece(Piece::Pawn(Color::White), &BoardPosition::new(File::D, Rank::Two).into()).unwrap();
+    game.board.spawn_piece(Piece::Pawn(Color::White), &BoardPosition::new(File::F, Rank::Two).into()).unwrap();
+    // Blocks the first square in front of the d-pawn
+    game.board.spawn_piece(Piece::Bishop(Color::Black), &BoardPosition::new(File::D, Rank::Three).into()).unwrap();
+    // Blocks the second square in front of the f-pawn
+    game.board.spawn_piece(Piece::Bishop(Color::Black), &BoardPosition::new(File::F, Rank::Four).into()).unwrap();
+
+    let moves = game.get_possible_moves(&BoardPosition::new(File::D, Rank::Two)).unwrap();
+    assert!(moves.is_empty());
+
+    let moves = game.get_possible_moves(&BoardPosition::new(File::F, Rank::Two)).unwrap();
+    assert_eq!(moves, vec![BoardPosition::new(File::F, Rank::Three)]);
+}
+
+#[test]
+fn test_pawn_cannot_capture_forward() {
+    let mut game = setup_empty_with_kings();
+    game.board.spawn_piece(Piece::Pawn(Color::White), &BoardPosition::new(File::D, Rank::Four).into()).unwrap();
+    game.board.spawn_piece(Piece::Pawn(Color::Black), &BoardPosition::new(File::D, Rank::Five).into()).unwrap();
+
+    let moves = game.get_possible_moves(&BoardPosition::new(File::D, Rank::Four)).unwrap();
+    assert!(moves.is_empty());
+
+    let result = game.move_piece(&BoardPosition::new(File::D, Rank::Four), &BoardPosition::new(File::D, Rank::Five));
+    assert_eq!(result, Err(ChessError::IllegalMove(IllegalMoveReason::PathBlocked)));
+}
+
+#[test]
+fn test_pawn_captures_diagonally() {
+    let mut game = setup_empty_with_kings();
+    game.board.spawn_piece(Piece::Pawn(Color::White), &BoardPosition::new(File::D, Rank::Four).into()).unwrap();
+    game.board.spawn_piece(Piece::Knight(Color::Black), &BoardPosition::new(File::C, Rank::Five).into()).unwrap();
+    // Friendly pieces cannot be captured
+    game.board.spawn_piece(Piece::Knight(Color::White), &BoardPosition::new(File::E, Rank::Five).into()).unwrap();
+
+    let moves = game.get_possible_moves(&BoardPosition::new(File::D, Rank::Four)).unwrap();
+    assert_eq!(moves, vec![BoardPosition::new(File::D, Rank::Five), BoardPosition::new(File::C, Rank::Five)]);
+
+    game.move_piece(&BoardPosition::new(File::D, Rank::Four), &BoardPosition::new(File::C, Rank::Five)).unwrap();
+    assert_eq!(game.get_piece(&BoardPosition::new(File::C, Rank::Five)), Some(Piece::Pawn(Color::White)));
+}
+
+#[test]
+fn test_black_pawn_captures_diagonally() {
+    let mut game = setup_empty_with_kings();
+    game.board.spawn_piece(Piece::Pawn(Color::White), &BoardPosition::new(File::A, Rank::Two).into()).unwrap();
+    game.board.spawn_piece(Piece::Pawn(Color::Black), &BoardPosition::new(File::D, Rank::Five).into()).unwrap();
+    game.board.spawn_piece(Piece::Knight(Color::White), &BoardPosition::new(File::E, Rank::Four).into()).unwrap();
+    //Move white to change the turn
+    game.move_piece(&BoardPosition::new(File::A, Rank::Two), &BoardPosition::new(File::A, Rank::Three)).unwrap();
+
+    let moves = game.get_possible_moves(&BoardPosition::new(File::D, Rank::Five)).unwrap();
+    assert_eq!(moves, vec![BoardPosition::new(File::D, Rank::Four), BoardPosition::new(File::E, Rank::Four)]);
+
+    let result = game.move_piece(&BoardPosition::new(File::D, Rank::Five), &BoardPosition::new(File::C, Rank::Four));
+    assert_eq!(result, Err(ChessError::IllegalMove(IllegalMoveReason::NothingToCapture)));
+}
+
+#[test]
+fn test_pawn_can_capture_en_passant() {
+    let mut game = Game::new();
+    game.move_piece(&BoardPosition::new(File::E, Rank::Two), &BoardPosition::new(File::E, Rank::Four)).unwrap();
+    game.move_piece(&BoardPosition::new(File::A, Rank::Seven), &BoardPosition::new(File::A, Rank::Six)).unwrap();
+    game.move_piece(&BoardPosition::new(File::E, Rank::Four), &BoardPosition::new(File::E, Rank::Five)).unwrap();
+    game.move_piece(&BoardPosition::new(File::D, Rank::Seven), &BoardPosition::new(File::D, Rank::Five)).unwrap();
+
+    assert_eq!(game.get_en_passant(), Some(BoardPosition::new(File::D, Rank::Six)));
+    let moves = game.get_possible_moves(&BoardPosition::new(File::E, Rank::Five)).unwrap();
+    assert!(moves.contains(&BoardPosition::new(File::D, Rank::Six)));
+
+    game.move_piece(&BoardPosition::new(File::E, Rank::Five), &BoardPosition::new(File::D, Rank::Six)).unwrap();
+    assert_eq!(game.get_piece(&BoardPosition::new(File::D, Rank::Six)), Some(Piece::Pawn(Color::White)));
+    assert_eq!(game.get_piece(&BoardPosition::new(File::D, Rank::Five)), None);
+    assert_eq!(game.get_en_passant(), None);
+}
+
+#[test]
+fn test_en_passant_is_only_available_right_after_the_double_step() {
+    let mut game = Game::new();
+    game.move_piece(&BoardPosition::new(File::E, Rank::Two), &BoardPosition::new(File::E, Rank::Four)).unwrap();
+    game.move_piece(&BoardPosition::new(File::A, Rank::Seven), &BoardPosition::new(File::A, Rank::Six)).unwrap();
+    game.move_piece(&BoardPosition::new(File::E, Rank::Four), &BoardPosition::new(File::E, Rank::Five)).unwrap();
+    game.move_piece(&BoardPosition::new(File::D, Rank::Seven), &BoardPosition::new(File::D, Rank::Five)).unwrap();
+    game.move_piece(&BoardPosition::new(File::A, Rank::Two), &BoardPosition::new(File::A, Rank::Three)).unwrap();
+    game.move_piece(&BoardPosition::new(File::A, Rank::Six), &BoardPosition::new(File::A, Rank::Five)).unwrap();
+
+    let result = game.move_piece(&BoardPosition::new(File::E, Rank::Five), &BoardPosition::new(File::D, Rank::Six));
+    assert_eq!(result, Err(ChessError::IllegalMove(IllegalMoveReason::NothingToCapture)));
+}
+
+#[test]
+fn test_en_passant_cannot_expose_the_king() {
+    let mut game = Game::empty();
+    game.board.spawn_piece(Piece::King(Color::White), &BoardPosition::new(File::A, Rank::Five).into()).unwrap();
+    game.board.spawn_piece(Piece::King(Color::Black), &BoardPosition::new(File::E, Rank::Eight).into()).unwrap();
+    game.board.spawn_piece(Piece::Rook(Color::Black), &BoardPosition::new(File::H, Rank::Five).into()).unwrap();
+    game.board.spawn_piece(Piece::Pawn(Color::White), &BoardPosition::new(File::B, Rank::Five).into()).unwrap();
+    game.board.spawn_piece(Piece::Pawn(Color::White), &BoardPosition::new(File::H, Rank::Two).into()).unwrap();
+    game.board.spawn_piece(Piece::Pawn(Color::Black), &BoardPosition::new(File::C, Rank::Seven).into()).unwrap();
+
+    //Move white to change the turn
+    game.move_piece(&BoardPosition::new(File::H, Rank::Two), &BoardPosition::new(File::H, Rank::Three)).unwrap();
+    game.move_piece(&BoardPosition::new(File::C, Rank::Seven), &BoardPosition::new(File::C, Rank::Five)).unwrap();
+
+    // Capturing would remove both pawns between the king and the rook
+    let moves = game.get_possible_moves(&BoardPosition::new(File::B, Rank::Five)).unwrap();
+    assert_eq!(moves, vec![BoardPosition::new(File::B, Rank::Six)]);
+    let result = game.move_piece(&BoardPosition::new(File::B, Rank::Five), &BoardPosition::new(File::C, Rank::Six));
+    assert_eq!(result, Err(ChessError::IllegalMove(IllegalMoveReason::LeavesKingInCheck)));
+    assert_eq!(game.get_piece(&BoardPosition::new(File::C, Rank::Five)), Some(Piece::Pawn(Color::Black)));
+}
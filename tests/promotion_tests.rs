@@ -0,0 +1,75 @@
+use olle_chess::*;
+use position::*;
+
+fn setup_pawn_on_seventh() -> Game {
+    let mut game = Game::empty();
+    game.board.spawn_piece(Piece::King(Color::White), &BoardPosition::new(File::E, Rank::One).into()).unwrap();
+    game.board.spawn_piece(Piece::King(Color::Black), &BoardPosition::new(File::H, Rank::Eight).into()).unwrap();
+    game.board.spawn_piece(Piece::Pawn(Color::White), &BoardPosition::new(File::A, Rank::Seven).into()).unwrap();
+    game.board.spawn_piece(Piece::Rook(Color::Black), &BoardPosition::new(File::B, Rank::Eight).into()).unwrap();
+    game
+}
+
+#[test]
+fn test_promotion_in_one_call() {
+    let mut game = setup_pawn_on_seventh();
+
+    let result = game.move_piece_with_promotion(&BoardPosition::new(File::A, Rank::Seven), &BoardPosition::new(File::A, Rank::Eight), Some(PieceKind::Knight));
+    assert_eq!(result, Ok(GameState::InProgress));
+    assert_eq!(game.get_piece(&BoardPosition::new(File::A, Rank::Eight)), Some(Piece::Knight(Color::White)));
+    assert_eq!(game.get_turn(), Color::Black);
+}
+
+#[test]
+fn test_promotion_with_capture_in_one_call() {
+    let mut game = setup_pawn_on_seventh();
+
+    let result = game.move_piece_with_promotion(&BoardPosition::new(File::A, Rank::Seven), &BoardPosition::new(File::B, Rank::Eight), Some(PieceKind::Rook));
+    assert_eq!(result, Ok(GameState::Check));
+    assert_eq!(game.get_piece(&BoardPosition::new(File::B, Rank::Eight)), Some(Piece::Rook(Color::White)));
+    assert_eq!(game.get_piece(&BoardPosition::new(File::A, Rank::Seven)), None);
+}
+
+#[test]
+fn test_promotion_to_king_or_pawn_is_an_error() {
+    let mut game = setup_pawn_on_seventh();
+
+    for kind in [PieceKind::King, PieceKind::Pawn] {
+        let result = game.move_piece_with_promotion(&BoardPosition::new(File::A, Rank::Seven), &BoardPosition::new(File::A, Rank::Eight), Some(kind));
+        assert_eq!(result, Err(ChessError::PromotionError));
+    }
+    assert_eq!(game.get_piece(&BoardPosition::new(File::A, Rank::Seven)), Some(Piece::Pawn(Color::White)));
+    assert_eq!(game.get_turn(), Color::White);
+}
+
+#[test]
+fn test_promotion_piece_for_a_normal_move_is_an_error() {
+    let mut game = setup_pawn_on_seventh();
+
+    let result = game.move_piece_with_promotion(&BoardPosition::new(File::E, Rank::One), &BoardPosition::new(File::E, Rank::Two), Some(PieceKind::Queen));
+    assert_eq!(result, Err(ChessError::PromotionError));
+}
+
+#[test]
+fn test_auto_queen_policy() {
+    let mut game = setup_pawn_on_seventh();
+    assert_eq!(game.get_promotion_policy(), PromotionPolicy::Ask);
+    game.set_promotion_policy(PromotionPolicy::AutoQueen);
+
+    let result = game.move_piece(&BoardPosition::new(File::A, Rank::Seven), &BoardPosition::new(File::A, Rank::Eight));
+    assert_eq!(result, Ok(GameState::InProgress));
+    assert_eq!(game.get_piece(&BoardPosition::new(File::A, Rank::Eight)), Some(Piece::Queen(Color::White)));
+    assert_eq!(game.get_turn(), Color::Black);
+}
+
+#[test]
+fn test_ask_policy_waits_for_promote_pawn() {
+    let mut game = setup_pawn_on_seventh();
+
+    let result = game.move_piece_with_promotion(&BoardPosition::new(File::A, Rank::Seven), &BoardPosition::new(File::B, Rank::Eight), None);
+    assert_eq!(result, Ok(GameState::Promotion(BoardPosition::new(File::B, Rank::Eight))));
+
+    let result = game.promote_pawn(Piece::Queen(Color::White));
+    assert_eq!(result, Ok(GameState::Check));
+    assert_eq!(game.get_piece(&BoardPosition::new(File::B, Rank::Eight)), Some(Piece::Queen(Color::White)));
+}
@@ -0,0 +1,165 @@
+use olle_chess::castling::*;
+use olle_chess::chess_move::*;
+use olle_chess::san::*;
+use olle_chess::*;
+use position::*;
+
+fn find_move(game: &mut Game, from: &str, to: &str, promotion: Option<PieceKind>) -> Move {
+    let from = BoardPosition::try_from(from).unwrap();
+    let to = BoardPosition::try_from(to).unwrap();
+    game.legal_moves()
+        .into_iter()
+        .find(|m| m.from == from && m.to == to && m.promotion == promotion)
+        .expect("move is not legal")
+}
+
+fn san(game: &mut Game, from: &str, to: &str) -> String {
+    let chess_move = find_move(game, from, to, None);
+    game.san(&chess_move)
+}
+
+#[test]
+fn test_pawn_and_piece_moves() {
+    let mut game = Game::new();
+    assert_eq!(san(&mut game, "e2", "e4"), "e4");
+    assert_eq!(san(&mut game, "g1", "f3"), "Nf3");
+}
+
+#[test]
+fn test_captures() {
+    let mut game = Game::from_fen("rnbqkbnr/ppp1pppp/8/3p4/4P3/5N2/PPPP1PPP/RNBQKB1R w KQkq - 0 2").unwrap();
+    assert_eq!(san(&mut game, "e4", "d5"), "exd5");
+    assert_eq!(san(&mut game, "f1", "b5"), "Bb5+");
+}
+
+#[test]
+fn test_en_passant() {
+    let mut game = Game::from_fen("rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 3").unwrap();
+    let chess_move = find_move(&mut game, "e5", "f6", None);
+    assert_eq!(chess_move.kind, MoveKind::EnPassant);
+    assert_eq!(game.san(&chess_move), "exf6");
+}
+
+#[test]
+fn test_castling() {
+    let mut game = Game::from_fen("r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1").unwrap();
+    let kingside = find_move(&mut game, "e1", "g1", None);
+    assert_eq!(kingside.kind, MoveKind::Castle(CastlingSide::Kingside));
+    assert_eq!(game.san(&kingside), "O-O");
+    assert_eq!(san(&mut game, "e1", "c1"), "O-O-O");
+}
+
+#[test]
+fn test_promotion() {
+    let mut game = Game::from_fen("1r5k/P7/8/8/8/8/8/4K3 w - - 0 1").unwrap();
+    let chess_move = find_move(&mut game, "a7", "a8", Some(PieceKind::Queen));
+    assert_eq!(game.san(&chess_move), "a8=Q");
+    let chess_move = find_move(&mut game, "a7", "b8", Some(PieceKind::Queen));
+    assert_eq!(game.san(&chess_move), "axb8=Q+");
+    let chess_move = find_move(&mut game, "a7", "b8", Some(PieceKind::Knight));
+    assert_eq!(game.san(&chess_move), "axb8=N");
+}
+
+#[test]
+fn test_disambiguation() {
+    //Knights on b1 and f1 can both reach d2, rooks on a1 and a5 can both reach a3
+    let mut game = Game::from_fen("7k/8/8/R7/8/8/8/RN2KN2 w - - 0 1").unwrap();
+    assert_eq!(san(&mut game, "b1", "d2"), "Nbd2");
+    assert_eq!(san(&mut game, "a1", "a3"), "R1a3");
+    assert_eq!(san(&mut game, "a5", "a3"), "R5a3");
+    assert_eq!(san(&mut game, "a5", "b5"), "Rb5");
+
+    //Three queens that each share a file or a rank with another need the whole square
+    let mut game = Game::from_fen("8/8/1k6/8/4Q2Q/8/8/K6Q w - - 0 1").unwrap();
+    assert_eq!(san(&mut game, "h4", "e1"), "Qh4e1");
+    assert_eq!(san(&mut game, "h1", "e1"), "Q1e1");
+    assert_eq!(san(&mut game, "e4", "e1"), "Qee1");
+}
+
+#[test]
+fn test_checkmate_suffix() {
+    let mut game = Game::from_fen("rnbqkbnr/pppp1ppp/8/4p3/6P1/5P2/PPPPP2P/RNBQKBNR b KQkq - 0 2").unwrap();
+    assert_eq!(san(&mut game, "d8", "h4"), "Qh4#");
+    //Making the move for the notation does not change the game
+    assert_eq!(game.get_turn(), Color::Black);
+    assert_eq!(game.get_game_state(), GameState::InProgress);
+}
+
+#[test]
+fn test_play_san_game() {
+    let mut game = Game::new();
+    for san in ["e4", "e5", "Nf3", "Nc6", "Bc4", "Nf6", "Ng5", "d5", "exd5", "Nxd5", "Nxf7", "Kxf7", "Qf3+", "Ke6", "Nc3"] {
+        game.play_san(san).unwrap();
+    }
+    assert_eq!(game.to_fen(), "r1bq1b1r/ppp3pp/2n1k3/3np3/2B5/2N2Q2/PPPP1PPP/R1B1K2R b KQ - 3 8");
+}
+
+#[test]
+fn test_san_round_trip() {
+    let mut game = Game::from_fen("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1").unwrap();
+    for chess_move in game.legal_moves() {
+        let san = game.san(&chess_move);
+        assert_eq!(game.parse_san(&san), Ok(chess_move), "{}", san);
+    }
+}
+
+#[test]
+fn test_common_variants() {
+    let mut game = Game::from_fen("r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1").unwrap();
+    let castle = game.parse_san("0-0").unwrap();
+    assert_eq!(castle.kind, MoveKind::Castle(CastlingSide::Kingside));
+    assert_eq!(game.parse_san("O-O-O+"), Ok(find_move(&mut game, "e1", "c1", None)));
+
+    let mut game = Game::from_fen("1r5k/P7/8/8/8/8/8/4K3 w - - 0 1").unwrap();
+    let promotion = find_move(&mut game, "a7", "a8", Some(PieceKind::Queen));
+    assert_eq!(game.parse_san("a8Q"), Ok(promotion));
+    assert_eq!(game.parse_san("a8=Q"), Ok(promotion));
+    assert_eq!(game.parse_san("a8=q!?"), Ok(promotion));
+    assert_eq!(game.parse_san("axb8=N"), Ok(find_move(&mut game, "a7", "b8", Some(PieceKind::Knight))));
+
+    let mut game = Game::from_fen("rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 3").unwrap();
+    let en_passant = find_move(&mut game, "e5", "f6", None);
+    assert_eq!(game.parse_san("exf6 e.p."), Ok(en_passant));
+    assert_eq!(game.parse_san("exf6e.p."), Ok(en_passant));
+    assert_eq!(game.parse_san("ef6"), Ok(en_passant));
+}
+
+#[test]
+fn test_san_errors() {
+    let mut game = Game::from_fen("7k/8/8/R7/8/8/8/RN2KN2 w - - 0 1").unwrap();
+    assert_eq!(game.parse_san("Nd2"), Err(SanError::AmbiguousMove));
+    assert_eq!(game.parse_san("Ra3"), Err(SanError::AmbiguousMove));
+    assert!(game.parse_san("Nbd2").is_ok());
+    assert_eq!(game.parse_san("Qd2"), Err(SanError::NoMatchingMove));
+    assert_eq!(game.parse_san("Ne9"), Err(SanError::InvalidCharacter('9')));
+    assert_eq!(game.parse_san("Nz3"), Err(SanError::InvalidCharacter('z')));
+    assert_eq!(game.parse_san("N"), Err(SanError::InvalidNotation));
+    assert_eq!(game.parse_san("+"), Err(SanError::InvalidNotation));
+    assert_eq!(game.play_san("Nd2"), Err(SanError::AmbiguousMove));
+    assert_eq!(game.get_turn(), Color::White);
+}
+
+#[test]
+fn test_pawn_push_does_not_match_captures() {
+    let mut game = Game::from_fen("4k3/8/8/3p4/4P3/8/8/4K3 w - - 0 1").unwrap();
+    assert_eq!(game.parse_san("d5"), Err(SanError::NoMatchingMove));
+    assert_eq!(game.play_san("d5"), Err(SanError::NoMatchingMove));
+    assert_eq!(game.parse_san("exd5"), Ok(find_move(&mut game, "e4", "d5", None)));
+    assert_eq!(game.parse_san("xd5"), Err(SanError::InvalidNotation));
+    assert_eq!(game.parse_san("exe5"), Err(SanError::NoMatchingMove));
+
+    let mut game = Game::from_fen("4k3/8/8/3p4/2P1P3/8/8/4K3 w - - 0 1").unwrap();
+    assert_eq!(game.parse_san("d5"), Err(SanError::NoMatchingMove));
+    assert_eq!(game.parse_san("cxd5"), Ok(find_move(&mut game, "c4", "d5", None)));
+    assert_eq!(game.parse_san("c5"), Ok(find_move(&mut game, "c4", "c5", None)));
+}
+
+#[test]
+fn test_play_san_after_game_over() {
+    let mut game = Game::new();
+    for san in ["f3", "e5", "g4", "Qh4#"] {
+        game.play_san(san).unwrap();
+    }
+    assert_eq!(game.get_game_state(), GameState::Checkmate(Color::Black));
+    assert_eq!(game.play_san("e3"), Err(SanError::MoveFailed(ChessError::GameAlreadyOver)));
+}
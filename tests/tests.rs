@@ -9,7 +9,7 @@ fn setup_empty_with_kings() -> Game {
     let mut game = Game::empty();
     game.board.spawn_piece(Piece::King(Color::White), &BoardPosition::new(File::E, Rank::One).into()).unwrap();
     game.board.spawn_piece(Piece::King(Color::Black), &BoardPosition::new(File::E, Rank::Eight).into()).unwrap();
-    return game;
+    game
 }
 
 // check test framework
@@ -46,12 +46,8 @@ fn move_set_is_some() {
     let bp1 = BoardPosition::new(File::B, Rank::Seven);
     let moves = game.get_possible_moves(&bp1);
 
-    if let Some(m) = moves {
-        println!("{m:?}");
-        assert!(true);
-    } else {
-        assert!(false);
-    }
+    println!("{moves:?}");
+    assert!(moves.is_some());
 }
 
 #[test]
@@ -197,15 +193,16 @@ fn test_winning_works() {
     let mut game = Game::empty();
     game.board.spawn_piece(Piece::King(Color::White), &BoardPosition::new(File::H, Rank::One).into()).unwrap();
     game.board.spawn_piece(Piece::King(Color::Black), &BoardPosition::new(File::H, Rank::Eight).into()).unwrap();
-    game.board.spawn_piece(Piece::Rook(Color::White), &BoardPosition::new(File::H, Rank::Two).into()).unwrap();
+    game.board.spawn_piece(Piece::Pawn(Color::Black), &BoardPosition::new(File::H, Rank::Seven).into()).unwrap();
+    game.board.spawn_piece(Piece::Rook(Color::White), &BoardPosition::new(File::G, Rank::Two).into()).unwrap();
     game.board.spawn_piece(Piece::Queen(Color::White), &BoardPosition::new(File::A, Rank::One).into()).unwrap();
 
     println!("{:?}", game);
-    let result = game.move_piece(&BoardPosition::new(File::A, Rank::One), &BoardPosition::new(File::H, Rank::Eight));
+    let result = game.move_piece(&BoardPosition::new(File::A, Rank::One), &BoardPosition::new(File::A, Rank::Eight));
     println!("{:?}", game);
     
     assert!(result.is_ok());
-    assert_eq!(game.get_game_state(), GameState::GameOver(Color::White));
+    assert_eq!(game.get_game_state(), GameState::Checkmate(Color::White));
 }
 
 #[test]
@@ -220,4 +217,39 @@ fn test_is_check_works_ok(){
 
     println!("{:?}", game);
     assert_eq!(game.get_game_state(), GameState::InProgress);
-}
\ No newline at end of file
+}
+#[test]
+fn test_pawn_gives_check_diagonally() {
+    let mut game = setup_empty_with_kings();
+    game.board.spawn_piece(Piece::Pawn(Color::White), &BoardPosition::new(File::D, Rank::Six).into()).unwrap();
+
+    let result = game.move_piece(&BoardPosition::new(File::D, Rank::Six), &BoardPosition::new(File::D, Rank::Seven));
+    assert_eq!(result, Ok(GameState::Check));
+}
+
+#[test]
+fn test_pawn_in_front_of_king_does_not_give_check() {
+    let mut game = setup_empty_with_kings();
+    game.board.spawn_piece(Piece::Pawn(Color::White), &BoardPosition::new(File::E, Rank::Six).into()).unwrap();
+
+    let result = game.move_piece(&BoardPosition::new(File::E, Rank::Six), &BoardPosition::new(File::E, Rank::Seven));
+    assert_eq!(result, Ok(GameState::InProgress));
+}
+
+#[test]
+fn test_king_cannot_step_into_pawn_attack() {
+    let mut game = setup_empty_with_kings();
+    game.board.spawn_piece(Piece::Pawn(Color::Black), &BoardPosition::new(File::E, Rank::Three).into()).unwrap();
+
+    let moves = game.get_possible_moves(&BoardPosition::new(File::E, Rank::One)).unwrap();
+    assert!(!moves.contains(&BoardPosition::new(File::D, Rank::Two)));
+    assert!(!moves.contains(&BoardPosition::new(File::F, Rank::Two)));
+    assert!(moves.contains(&BoardPosition::new(File::E, Rank::Two)));
+}
+
+#[test]
+fn board_position_displays_like_chess_notation() {
+    let bp = BoardPosition::new(File::E, Rank::Four);
+    assert_eq!(bp.to_string(), "e4");
+    assert_eq!(format!("{:?}", bp), "E4");
+}
@@ -0,0 +1,77 @@
+use olle_chess::castling::*;
+use olle_chess::chess_move::*;
+use olle_chess::uci::*;
+use olle_chess::*;
+use position::*;
+
+#[test]
+fn test_moves_are_written_in_uci() {
+    let mut game = Game::new();
+    let e4 = game.parse_uci("e2e4").unwrap();
+    assert_eq!(e4, Move::new(BoardPosition::new(File::E, Rank::Two), BoardPosition::new(File::E, Rank::Four), None, MoveKind::DoubleStep));
+    assert_eq!(e4.to_string(), "e2e4");
+    assert_eq!(Move::null().to_string(), "0000");
+
+    let promotion = Move::new(BoardPosition::new(File::E, Rank::Seven), BoardPosition::new(File::F, Rank::Eight), Some(PieceKind::Knight), MoveKind::Capture);
+    assert_eq!(promotion.to_string(), "e7f8n");
+}
+
+#[test]
+fn test_every_legal_move_round_trips() {
+    let mut game = Game::from_fen("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1").unwrap();
+    for m in game.legal_moves() {
+        assert_eq!(game.parse_uci(&m.to_string()), Ok(m));
+    }
+}
+
+#[test]
+fn test_castling_promotion_and_uppercase() {
+    let mut game = Game::from_fen("4k3/1P6/8/8/8/8/8/R3K2R w KQ - 0 1").unwrap();
+    let castle = game.parse_uci("e1g1").unwrap();
+    assert_eq!(castle.kind, MoveKind::Castle(CastlingSide::Kingside));
+    assert_eq!(game.parse_uci("E1C1").unwrap().kind, MoveKind::Castle(CastlingSide::Queenside));
+    assert_eq!(game.parse_uci("b7b8Q").unwrap().promotion, Some(PieceKind::Queen));
+    assert_eq!(game.parse_uci(" b7b8r ").unwrap().promotion, Some(PieceKind::Rook));
+
+    assert_eq!(game.play_uci("b7b8q"), Ok(GameState::Check));
+    assert_eq!(game.get_piece(&BoardPosition::new(File::B, Rank::Eight)), Some(Piece::Queen(Color::White)));
+}
+
+#[test]
+fn test_invalid_uci() {
+    let mut game = Game::new();
+    assert_eq!(game.parse_uci(""), Err(UciError::InvalidNotation));
+    assert_eq!(game.parse_uci("e2e4qq"), Err(UciError::InvalidNotation));
+    assert_eq!(game.parse_uci("e2i4"), Err(UciError::InvalidCharacter('i')));
+    assert_eq!(game.parse_uci("e2e9"), Err(UciError::InvalidCharacter('9')));
+    assert_eq!(game.parse_uci("e7e8k"), Err(UciError::InvalidCharacter('k')));
+    assert_eq!(game.parse_uci("e2e5"), Err(UciError::NoMatchingMove));
+    assert_eq!(game.parse_uci("e2e4q"), Err(UciError::NoMatchingMove));
+    assert_eq!(game.parse_uci("e7e5"), Err(UciError::NoMatchingMove));
+}
+
+#[test]
+fn test_null_move() {
+    let mut game = Game::from_fen("4k3/8/8/8/3pP3/8/8/4K3 b - e3 0 1").unwrap();
+    let null = game.parse_uci("0000").unwrap();
+    assert!(null.is_null());
+    assert_eq!(game.play_uci("0000"), Err(UciError::NullMove));
+
+    let piece_array = game.board.piece_array;
+    let undo = game.make_move(null);
+    assert_eq!(game.get_turn(), Color::White);
+    assert_eq!(game.get_en_passant(), None);
+    assert_eq!(game.board.piece_array, piece_array);
+    game.unmake_move(undo);
+    assert_eq!(game.to_fen(), "4k3/8/8/8/3pP3/8/8/4K3 b - e3 0 1");
+}
+
+#[test]
+fn test_play_uci() {
+    let mut game = Game::new();
+    for uci in ["f2f3", "e7e5", "g2g4"] {
+        assert_eq!(game.play_uci(uci), Ok(GameState::InProgress));
+    }
+    assert_eq!(game.play_uci("d8h4"), Ok(GameState::Checkmate(Color::Black)));
+    assert_eq!(game.play_uci("a2a3"), Err(UciError::MoveFailed(ChessError::GameAlreadyOver)));
+}
@@ -0,0 +1,163 @@
+use olle_chess::*;
+use position::*;
+
+fn play(game: &mut Game, from: (File, Rank), to: (File, Rank)) -> GameState {
+    game.move_piece(&BoardPosition::new(from.0, from.1), &BoardPosition::new(to.0, to.1)).unwrap()
+}
+
+#[test]
+fn test_nothing_to_undo_or_redo() {
+    let mut game = Game::new();
+    assert_eq!(game.undo(), Err(ChessError::NothingToUndo));
+    assert_eq!(game.redo(), Err(ChessError::NothingToRedo));
+}
+
+#[test]
+fn test_undo_restores_the_position() {
+    let mut game = Game::new();
+    let start = game.board.piece_array;
+    play(&mut game, (File::E, Rank::Two), (File::E, Rank::Four));
+    let after_one = game.board.piece_array;
+    play(&mut game, (File::G, Rank::Eight), (File::F, Rank::Six));
+
+    assert_eq!(game.undo(), Ok(GameState::InProgress));
+    assert_eq!(game.board.piece_array, after_one);
+    assert_eq!(game.get_turn(), Color::Black);
+    assert_eq!(game.get_en_passant(), Some(BoardPosition::new(File::E, Rank::Three)));
+
+    assert_eq!(game.undo(), Ok(GameState::InProgress));
+    assert_eq!(game.board.piece_array, start);
+    assert_eq!(game.get_turn(), Color::White);
+    assert_eq!(game.get_en_passant(), None);
+    assert_eq!(game.get_halfmove_clock(), 0);
+    assert_eq!(game.get_fullmove_number(), 1);
+    assert!(game.get_move_history().is_empty());
+}
+
+#[test]
+fn test_redo_makes_the_undone_moves_again() {
+    let mut game = Game::new();
+    play(&mut game, (File::E, Rank::Two), (File::E, Rank::Four));
+    play(&mut game, (File::G, Rank::Eight), (File::F, Rank::Six));
+    let position = game.board.piece_array;
+    let history = game.get_move_history();
+
+    game.undo().unwrap();
+    game.undo().unwrap();
+    game.redo().unwrap();
+    game.redo().unwrap();
+
+    assert_eq!(game.board.piece_array, position);
+    assert_eq!(game.get_move_history(), history);
+    assert_eq!(game.get_turn(), Color::White);
+    assert_eq!(game.redo(), Err(ChessError::NothingToRedo));
+}
+
+#[test]
+fn test_new_move_discards_undone_moves() {
+    let mut game = Game::new();
+    play(&mut game, (File::E, Rank::Two), (File::E, Rank::Four));
+    game.undo().unwrap();
+    play(&mut game, (File::D, Rank::Two), (File::D, Rank::Four));
+
+    assert_eq!(game.redo(), Err(ChessError::NothingToRedo));
+    assert_eq!(game.get_move_history().len(), 1);
+    assert_eq!(game.get_move_history()[0].from, BoardPosition::new(File::D, Rank::Two));
+}
+
+#[test]
+fn test_undo_checkmate() {
+    let mut game = Game::new();
+    play(&mut game, (File::F, Rank::Two), (File::F, Rank::Three));
+    play(&mut game, (File::E, Rank::Seven), (File::E, Rank::Five));
+    play(&mut game, (File::G, Rank::Two), (File::G, Rank::Four));
+    assert_eq!(play(&mut game, (File::D, Rank::Eight), (File::H, Rank::Four)), GameState::Checkmate(Color::Black));
+
+    assert_eq!(game.undo(), Ok(GameState::InProgress));
+    assert!(!game.is_game_over());
+    assert_eq!(game.get_turn(), Color::Black);
+    assert!(game.get_castling_rights().has(Color::Black, castling::CastlingSide::Kingside));
+
+    assert_eq!(game.redo(), Ok(GameState::Checkmate(Color::Black)));
+}
+
+#[test]
+fn test_undo_waiting_promotion() {
+    let mut game = Game::empty();
+    game.board.spawn_piece(Piece::King(Color::White), &BoardPosition::new(File::E, Rank::One).into()).unwrap();
+    game.board.spawn_piece(Piece::King(Color::Black), &BoardPosition::new(File::H, Rank::Eight).into()).unwrap();
+    game.board.spawn_piece(Piece::Pawn(Color::White), &BoardPosition::new(File::A, Rank::Seven).into()).unwrap();
+
+    play(&mut game, (File::A, Rank::Seven), (File::A, Rank::Eight));
+    assert_eq!(game.undo(), Ok(GameState::InProgress));
+    assert_eq!(game.get_piece(&BoardPosition::new(File::A, Rank::Seven)), Some(Piece::Pawn(Color::White)));
+    assert_eq!(game.get_piece(&BoardPosition::new(File::A, Rank::Eight)), None);
+
+    assert_eq!(game.redo(), Ok(GameState::Promotion(BoardPosition::new(File::A, Rank::Eight))));
+    assert_eq!(game.promote_pawn(Piece::Queen(Color::White)), Ok(GameState::Check));
+    assert_eq!(game.undo(), Ok(GameState::InProgress));
+    assert_eq!(game.get_piece(&BoardPosition::new(File::A, Rank::Seven)), Some(Piece::Pawn(Color::White)));
+}
+
+#[test]
+fn test_undo_forgets_repeated_positions() {
+    let mut game = Game::new();
+    for _ in 0..2 {
+        play(&mut game, (File::G, Rank::One), (File::F, Rank::Three));
+        play(&mut game, (File::G, Rank::Eight), (File::F, Rank::Six));
+        play(&mut game, (File::F, Rank::Three), (File::G, Rank::One));
+        play(&mut game, (File::F, Rank::Six), (File::G, Rank::Eight));
+    }
+    assert_eq!(game.get_repetition_count(), 3);
+
+    game.undo().unwrap();
+    game.undo().unwrap();
+    game.undo().unwrap();
+    game.undo().unwrap();
+    assert_eq!(game.get_repetition_count(), 2);
+    assert_eq!(game.get_halfmove_clock(), 4);
+}
+
+#[test]
+fn test_no_redo_after_game_ends_without_a_move() {
+    let mut game = Game::new();
+    play(&mut game, (File::E, Rank::Two), (File::E, Rank::Four));
+    game.undo().unwrap();
+    assert_eq!(game.resign(Color::White), Ok(GameState::Resigned(Color::White)));
+    assert_eq!(game.redo(), Err(ChessError::GameAlreadyOver));
+    assert_eq!(game.get_game_state(), GameState::Resigned(Color::White));
+
+    let mut game = Game::new();
+    play(&mut game, (File::E, Rank::Two), (File::E, Rank::Four));
+    game.undo().unwrap();
+    game.offer_draw(Color::White).unwrap();
+    assert_eq!(game.accept_draw(Color::Black), Ok(GameState::Draw(DrawReason::Agreement)));
+    assert_eq!(game.redo(), Err(ChessError::GameAlreadyOver));
+    assert_eq!(game.get_move_history(), vec![]);
+}
+
+#[test]
+fn test_undo_keeps_games_ended_without_a_move() {
+    let mut game = Game::new();
+    play(&mut game, (File::E, Rank::Two), (File::E, Rank::Four));
+    game.resign(Color::Black).unwrap();
+    assert_eq!(game.undo(), Err(ChessError::GameAlreadyOver));
+    assert_eq!(game.get_game_state(), GameState::Resigned(Color::Black));
+    assert_eq!(game.get_move_history().len(), 1);
+
+    let mut game = Game::new();
+    play(&mut game, (File::E, Rank::Two), (File::E, Rank::Four));
+    game.time_out(Color::Black).unwrap();
+    assert_eq!(game.undo(), Err(ChessError::GameAlreadyOver));
+    assert_eq!(game.get_game_state(), GameState::TimedOut(Color::Black));
+
+    let mut game = Game::new();
+    for _ in 0..2 {
+        play(&mut game, (File::G, Rank::One), (File::F, Rank::Three));
+        play(&mut game, (File::G, Rank::Eight), (File::F, Rank::Six));
+        play(&mut game, (File::F, Rank::Three), (File::G, Rank::One));
+        play(&mut game, (File::F, Rank::Six), (File::G, Rank::Eight));
+    }
+    assert_eq!(game.claim_draw(), Ok(GameState::Draw(DrawReason::ThreefoldRepetition)));
+    assert_eq!(game.undo(), Err(ChessError::GameAlreadyOver));
+}